@@ -0,0 +1,98 @@
+//! Surfaceless rendering: render a [`GaussianCloud`] to an [`image::RgbaImage`] without opening
+//! a `winit` window. [`AppState`](crate::state::AppState)'s turntable export reuses the GUI's
+//! [`GfxState`](crate::gfx::GfxState) device, which only exists once a window surface has been
+//! created; this module creates its own device with no surface at all, so it works from CI,
+//! server-side thumbnailing, and dataset preview generation.
+
+use std::path::{Path, PathBuf};
+
+use egui_wgpu::wgpu;
+use gj_core::gaussian_cloud::GaussianCloud;
+use gj_lgm::preprocessing::CameraInfo;
+use gj_splat::camera::Camera;
+use gj_splat::renderer::GaussianRenderer;
+
+/// Request an adapter/device/queue with `compatible_surface: None`, so no window is required.
+async fn create_headless_device() -> anyhow::Result<(wgpu::Device, wgpu::Queue, wgpu::TextureFormat)> {
+    let instance = wgpu::Instance::default();
+
+    let adapter = instance
+        .request_adapter(&wgpu::RequestAdapterOptions {
+            power_preference: wgpu::PowerPreference::default(),
+            compatible_surface: None,
+            force_fallback_adapter: false,
+        })
+        .await
+        .map_err(|e| anyhow::anyhow!("No GPU adapter available for headless rendering: {}", e))?;
+
+    let (device, queue) = adapter
+        .request_device(&wgpu::DeviceDescriptor::default())
+        .await?;
+
+    Ok((device, queue, wgpu::TextureFormat::Rgba8UnormSrgb))
+}
+
+/// Map a [`CameraInfo`] orbit pose onto the [`Camera`] type `GaussianRenderer` expects. Only the
+/// orbit fields (`azimuth`/`elevation`/`radius`) and `fy` (for the field of view) are used; cameras built from an explicit
+/// [`CameraInfo::pose`] aren't supported by this path yet, since `Camera` has no way to take a
+/// raw view matrix.
+fn camera_info_to_camera(info: &CameraInfo, width: u32, height: u32) -> Camera {
+    let mut camera = Camera::default();
+    camera.azimuth = info.azimuth;
+    camera.elevation = info.elevation;
+    camera.distance = info.radius;
+    camera.aspect_ratio = width as f32 / height as f32;
+    camera.fov = 2.0 * (height as f32 / (2.0 * info.fy)).atan().to_degrees();
+    camera.update_position();
+    camera
+}
+
+/// Render `cloud` from `camera_info`'s point of view into an offscreen texture and read it back
+/// as an RGBA image, without a `winit` window or swapchain anywhere in the picture.
+pub async fn render_to_image(
+    cloud: &GaussianCloud,
+    camera_info: &CameraInfo,
+    width: u32,
+    height: u32,
+) -> anyhow::Result<image::RgbaImage> {
+    let (device, queue, format) = create_headless_device().await?;
+    let mut renderer = GaussianRenderer::new(device, queue, format).await;
+    renderer.load_gaussians(cloud);
+
+    let camera = camera_info_to_camera(camera_info, width, height);
+    Ok(renderer.render_to_rgba(&camera, width, height).await)
+}
+
+/// Render a turntable sequence of `frames` images sweeping a full orbit around `camera_info`'s
+/// azimuth, writing `frame_0000.png`, `frame_0001.png`, ... into `out_dir`. Returns the written
+/// paths in frame order.
+pub async fn render_turntable(
+    cloud: &GaussianCloud,
+    camera_info: &CameraInfo,
+    frames: usize,
+    width: u32,
+    height: u32,
+    out_dir: &Path,
+) -> anyhow::Result<Vec<PathBuf>> {
+    std::fs::create_dir_all(out_dir)?;
+
+    let (device, queue, format) = create_headless_device().await?;
+    let mut renderer = GaussianRenderer::new(device, queue, format).await;
+    renderer.load_gaussians(cloud);
+
+    let mut camera_info = camera_info.clone();
+    let start_azimuth = camera_info.azimuth;
+    let mut paths = Vec::with_capacity(frames);
+
+    for i in 0..frames {
+        camera_info.azimuth = start_azimuth + 360.0 * (i as f32) / (frames.max(1) as f32);
+        let camera = camera_info_to_camera(&camera_info, width, height);
+
+        let image = renderer.render_to_rgba(&camera, width, height).await;
+        let frame_path = out_dir.join(format!("frame_{i:04}.png"));
+        image.save(&frame_path)?;
+        paths.push(frame_path);
+    }
+
+    Ok(paths)
+}
@@ -0,0 +1,179 @@
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rusqlite::{params, Connection, OptionalExtension};
+
+/// A submitted generation job as recorded in the store: enough to resume polling after a
+/// restart, or to show past generations and reload their PLYs without re-running inference.
+#[derive(Clone, Debug)]
+pub struct JobRecord {
+    pub job_id: String,
+    pub prompt: String,
+    pub model: String,
+    pub guidance_scale: f32,
+    pub num_inference_steps: usize,
+    pub status: String,
+    pub output_path: Option<String>,
+    pub error: Option<String>,
+    pub created_at: i64,
+}
+
+/// Job states for which [`InferenceWorker::new`] should automatically re-attach a poller on
+/// startup - mirrors the Celery-style states `poll_job_status` already switches on.
+const RESUMABLE_STATES: [&str; 3] = ["PENDING", "STARTED", "RETRY"];
+
+/// SQLite-backed record of submitted jobs (like build-o-tron's `dbctx`/`state.db`), so in-flight
+/// generations survive an app restart instead of being silently dropped. Cheap to clone - the
+/// connection is shared behind a mutex, since `InferenceWorker` hands a clone to every poller
+/// thread as well as to the main loop.
+#[derive(Clone)]
+pub struct JobStore {
+    conn: Arc<Mutex<Connection>>,
+}
+
+impl JobStore {
+    /// Open (creating if needed) the SQLite database at `path` and ensure its schema exists.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, String> {
+        if let Some(parent) = path.as_ref().parent() {
+            std::fs::create_dir_all(parent).map_err(|e| format!("Failed to create job store dir: {}", e))?;
+        }
+
+        let conn = Connection::open(path).map_err(|e| format!("Failed to open job store: {}", e))?;
+
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS jobs (
+                job_id              TEXT PRIMARY KEY,
+                prompt              TEXT NOT NULL,
+                model               TEXT NOT NULL,
+                guidance_scale      REAL NOT NULL,
+                num_inference_steps INTEGER NOT NULL,
+                status              TEXT NOT NULL,
+                output_path         TEXT,
+                error               TEXT,
+                created_at          INTEGER NOT NULL
+            )",
+            [],
+        ).map_err(|e| format!("Failed to create jobs table: {}", e))?;
+
+        Ok(Self { conn: Arc::new(Mutex::new(conn)) })
+    }
+
+    /// Record a freshly submitted job as `PENDING`.
+    pub fn insert_job(
+        &self,
+        job_id: &str,
+        prompt: &str,
+        model: &str,
+        guidance_scale: f32,
+        num_inference_steps: usize,
+    ) -> Result<(), String> {
+        let created_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or(0);
+
+        self.conn.lock().unwrap().execute(
+            "INSERT OR REPLACE INTO jobs
+                (job_id, prompt, model, guidance_scale, num_inference_steps, status, output_path, error, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, 'PENDING', NULL, NULL, ?6)",
+            params![job_id, prompt, model, guidance_scale, num_inference_steps as i64, created_at],
+        ).map_err(|e| format!("Failed to insert job {}: {}", job_id, e))?;
+
+        Ok(())
+    }
+
+    /// Update a job's state as `poll_job_status` observes a transition.
+    pub fn update_status(&self, job_id: &str, status: &str) -> Result<(), String> {
+        self.conn.lock().unwrap().execute(
+            "UPDATE jobs SET status = ?1 WHERE job_id = ?2",
+            params![status, job_id],
+        ).map_err(|e| format!("Failed to update job {}: {}", job_id, e))?;
+
+        Ok(())
+    }
+
+    /// Record the final PLY path for a job that reached `SUCCESS`.
+    pub fn complete_job(&self, job_id: &str, output_path: &str) -> Result<(), String> {
+        self.conn.lock().unwrap().execute(
+            "UPDATE jobs SET status = 'SUCCESS', output_path = ?1 WHERE job_id = ?2",
+            params![output_path, job_id],
+        ).map_err(|e| format!("Failed to complete job {}: {}", job_id, e))?;
+
+        Ok(())
+    }
+
+    /// Record a job that reached `FAILURE` (or was cancelled) along with why.
+    pub fn fail_job(&self, job_id: &str, status: &str, error: &str) -> Result<(), String> {
+        self.conn.lock().unwrap().execute(
+            "UPDATE jobs SET status = ?1, error = ?2 WHERE job_id = ?3",
+            params![status, error, job_id],
+        ).map_err(|e| format!("Failed to mark job {} failed: {}", job_id, e))?;
+
+        Ok(())
+    }
+
+    /// Look up a single job's record by ID, e.g. so a completion notification can report the
+    /// prompt/model that produced it without the caller having to carry them around separately.
+    pub fn get_job(&self, job_id: &str) -> Result<Option<JobRecord>, String> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT job_id, prompt, model, guidance_scale, num_inference_steps, status, output_path, error, created_at
+             FROM jobs WHERE job_id = ?1"
+        ).map_err(|e| format!("Failed to query job {}: {}", job_id, e))?;
+
+        stmt.query_row(params![job_id], |row| {
+            Ok(JobRecord {
+                job_id: row.get(0)?,
+                prompt: row.get(1)?,
+                model: row.get(2)?,
+                guidance_scale: row.get(3)?,
+                num_inference_steps: row.get::<_, i64>(4)? as usize,
+                status: row.get(5)?,
+                output_path: row.get(6)?,
+                error: row.get(7)?,
+                created_at: row.get(8)?,
+            })
+        }).optional().map_err(|e| format!("Failed to read job {}: {}", job_id, e))
+    }
+
+    /// Job IDs still in [`RESUMABLE_STATES`] as of the last run, for `InferenceWorker::new` to
+    /// re-attach pollers to.
+    pub fn resumable_job_ids(&self) -> Result<Vec<String>, String> {
+        let conn = self.conn.lock().unwrap();
+        let placeholders = RESUMABLE_STATES.iter().map(|_| "?").collect::<Vec<_>>().join(", ");
+        let sql = format!("SELECT job_id FROM jobs WHERE status IN ({})", placeholders);
+
+        let mut stmt = conn.prepare(&sql).map_err(|e| format!("Failed to query resumable jobs: {}", e))?;
+        let rows = stmt
+            .query_map(rusqlite::params_from_iter(RESUMABLE_STATES.iter()), |row| row.get::<_, String>(0))
+            .map_err(|e| format!("Failed to read resumable jobs: {}", e))?;
+
+        rows.collect::<Result<Vec<_>, _>>().map_err(|e| format!("Failed to read resumable jobs: {}", e))
+    }
+
+    /// Full job history, most recent first, for the UI to show past generations.
+    pub fn list_jobs(&self) -> Result<Vec<JobRecord>, String> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT job_id, prompt, model, guidance_scale, num_inference_steps, status, output_path, error, created_at
+             FROM jobs ORDER BY created_at DESC"
+        ).map_err(|e| format!("Failed to query jobs: {}", e))?;
+
+        let rows = stmt.query_map([], |row| {
+            Ok(JobRecord {
+                job_id: row.get(0)?,
+                prompt: row.get(1)?,
+                model: row.get(2)?,
+                guidance_scale: row.get(3)?,
+                num_inference_steps: row.get::<_, i64>(4)? as usize,
+                status: row.get(5)?,
+                output_path: row.get(6)?,
+                error: row.get(7)?,
+                created_at: row.get(8)?,
+            })
+        }).map_err(|e| format!("Failed to read jobs: {}", e))?;
+
+        rows.collect::<Result<Vec<_>, _>>().map_err(|e| format!("Failed to read jobs: {}", e))
+    }
+}
@@ -1,6 +1,11 @@
 mod app;
+mod headless;
+mod job_store;
+mod notifier;
+mod scripting;
 mod state;
 mod ui;
+mod worker;
 
 use egui_wgpu::wgpu;
 use winit::event::{ElementState, Event, KeyEvent, WindowEvent};
@@ -0,0 +1,104 @@
+use serde::Serialize;
+
+use crate::job_store::JobStore;
+
+/// Which sinks fire when a job reaches `SUCCESS` or `FAILURE`. Patterned on build-o-tron's
+/// `notifier` module: a config struct of independent, individually-optional sinks rather than one
+/// on/off switch, since a headless run wants the webhook but not the desktop popup.
+#[derive(Clone, Debug, Default)]
+pub struct NotifierConfig {
+    /// Show a desktop notification via the OS notification center.
+    pub desktop_enabled: bool,
+    /// POST a JSON payload describing the completed job to this URL, if set.
+    pub webhook_url: Option<String>,
+}
+
+/// Payload POSTed to `webhook_url` on completion - deliberately a subset of [`JobRecord`] rather
+/// than the record itself, so the wire format doesn't change shape if the store schema grows.
+#[derive(Serialize)]
+struct WebhookPayload<'a> {
+    job_id: &'a str,
+    prompt: &'a str,
+    model: &'a str,
+    output_path: Option<&'a str>,
+    error: Option<&'a str>,
+}
+
+/// Dispatches completion notifications for finished generations, so the user finds out a job
+/// succeeded or failed even when the window isn't focused. Cheap to clone - handed to every
+/// poller thread the same way [`JobStore`] is.
+#[derive(Clone, Default)]
+pub struct Notifier {
+    config: NotifierConfig,
+}
+
+impl Notifier {
+    pub fn new(config: NotifierConfig) -> Self {
+        Self { config }
+    }
+
+    /// Fire whichever sinks are enabled for a job that just reached `SUCCESS` or `FAILURE`.
+    /// Looks the job's prompt/model up in `store` so callers only need to pass the job ID and
+    /// outcome. Best-effort: a failed sink is logged to stderr, never propagated - a broken
+    /// webhook shouldn't take down the poller that's reporting a real result.
+    pub fn notify_completion(&self, store: &JobStore, job_id: &str, output_path: Option<&str>, error: Option<&str>) {
+        if !self.config.desktop_enabled && self.config.webhook_url.is_none() {
+            return;
+        }
+
+        let record = match store.get_job(job_id) {
+            Ok(Some(record)) => record,
+            Ok(None) => {
+                eprintln!("notifier: job {} not found in store, skipping notification", job_id);
+                return;
+            }
+            Err(e) => {
+                eprintln!("notifier: failed to look up job {}: {}", job_id, e);
+                return;
+            }
+        };
+
+        if self.config.desktop_enabled {
+            self.notify_desktop(&record.prompt, error);
+        }
+
+        if let Some(url) = &self.config.webhook_url {
+            self.notify_webhook(url, job_id, &record.prompt, &record.model, output_path, error);
+        }
+    }
+
+    fn notify_desktop(&self, prompt: &str, error: Option<&str>) {
+        let (summary, body) = match error {
+            Some(message) => ("Generation failed".to_string(), message.to_string()),
+            None => ("Generation complete".to_string(), format!("\"{}\" is ready", prompt)),
+        };
+
+        if let Err(e) = notify_rust::Notification::new()
+            .summary(&summary)
+            .body(&body)
+            .appname("3D Generation Studio")
+            .show()
+        {
+            eprintln!("notifier: failed to show desktop notification: {}", e);
+        }
+    }
+
+    fn notify_webhook(&self, url: &str, job_id: &str, prompt: &str, model: &str, output_path: Option<&str>, error: Option<&str>) {
+        let payload = WebhookPayload { job_id, prompt, model, output_path, error };
+
+        let result = reqwest::blocking::Client::new()
+            .post(url)
+            .json(&payload)
+            .send();
+
+        match result {
+            Ok(response) if !response.status().is_success() => {
+                eprintln!("notifier: webhook {} returned {}", url, response.status());
+            }
+            Err(e) => {
+                eprintln!("notifier: failed to reach webhook {}: {}", url, e);
+            }
+            Ok(_) => {}
+        }
+    }
+}
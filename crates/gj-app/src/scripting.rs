@@ -0,0 +1,77 @@
+use std::sync::mpsc::Sender;
+
+use rhai::{Engine, EvalAltResult};
+
+/// Commands a running script issues back to [`AppState`](crate::state::AppState), which applies
+/// them on the main thread the next time it drains `script_cmd_rx` in `update()`.
+#[derive(Clone, Debug)]
+pub enum ScriptCommand {
+    SetPosition([f32; 3]),
+    LookAt([f32; 3]),
+    Orbit { yaw: f32, pitch: f32, radius: f32 },
+    Generate { prompt: String, model: String },
+    Capture { path: String },
+}
+
+/// Runs a Rhai script with `camera`/`generate`/`capture` bindings wired to a [`ScriptCommand`]
+/// channel. Scripts execute on the caller's thread (the app's blocking task pool), so they can
+/// `sleep` between keyframes while the main loop keeps rendering.
+pub struct ScriptRunner {
+    engine: Engine,
+}
+
+impl ScriptRunner {
+    pub fn new(command_tx: Sender<ScriptCommand>, status_tx: Sender<String>) -> Self {
+        let mut engine = Engine::new();
+
+        let tx = command_tx.clone();
+        engine.register_fn("set_position", move |x: f64, y: f64, z: f64| {
+            let _ = tx.send(ScriptCommand::SetPosition([x as f32, y as f32, z as f32]));
+        });
+
+        let tx = command_tx.clone();
+        engine.register_fn("look_at", move |x: f64, y: f64, z: f64| {
+            let _ = tx.send(ScriptCommand::LookAt([x as f32, y as f32, z as f32]));
+        });
+
+        let tx = command_tx.clone();
+        engine.register_fn("orbit", move |yaw: f64, pitch: f64, radius: f64| {
+            let _ = tx.send(ScriptCommand::Orbit {
+                yaw: yaw as f32,
+                pitch: pitch as f32,
+                radius: radius as f32,
+            });
+        });
+
+        let tx = command_tx.clone();
+        engine.register_fn("generate", move |prompt: &str, model: &str| {
+            let _ = tx.send(ScriptCommand::Generate {
+                prompt: prompt.to_string(),
+                model: model.to_string(),
+            });
+        });
+
+        let tx = command_tx.clone();
+        engine.register_fn("capture", move |path: &str| {
+            let _ = tx.send(ScriptCommand::Capture { path: path.to_string() });
+        });
+
+        let status = status_tx.clone();
+        engine.register_fn("log", move |message: &str| {
+            let _ = status.send(message.to_string());
+        });
+
+        let status = status_tx;
+        engine.register_fn("sleep", move |seconds: f64| {
+            let _ = status.send(format!("script: sleeping {seconds:.2}s"));
+            std::thread::sleep(std::time::Duration::from_secs_f64(seconds.max(0.0)));
+        });
+
+        Self { engine }
+    }
+
+    /// Run a script to completion. Blocks the calling thread for the script's duration.
+    pub fn run(&self, script: &str) -> Result<(), Box<EvalAltResult>> {
+        self.engine.run(script)
+    }
+}
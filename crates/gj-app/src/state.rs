@@ -13,6 +13,7 @@ use gj_splat::renderer::GaussianRenderer;
 
 use crate::events::{AppEvent, UiEvent};
 use crate::gfx::GfxState;
+use crate::scripting::{ScriptCommand, ScriptRunner};
 use crate::worker::{InferenceWorker, WorkerResponse};
 use crate::ui::UiState;
 use crate::worker;
@@ -40,6 +41,10 @@ pub struct AppState {
 
     // Tokio runtime for background tasks
     pub rt: tokio::runtime::Runtime,
+
+    // Scripting: commands a running script sends back to the main loop
+    script_cmd_tx: std::sync::mpsc::Sender<ScriptCommand>,
+    script_cmd_rx: std::sync::mpsc::Receiver<ScriptCommand>,
 }
 
 impl AppState {
@@ -64,6 +69,8 @@ impl AppState {
             .enable_all()
             .build()?;
 
+        let (script_cmd_tx, script_cmd_rx) = std::sync::mpsc::channel();
+
         Ok(Self {
             window,
             renderer,
@@ -80,6 +87,9 @@ impl AppState {
             last_mouse_pos: None,
 
             rt,
+
+            script_cmd_tx,
+            script_cmd_rx,
         })
     }
 
@@ -159,6 +169,14 @@ impl AppState {
                     self.status = format!("Error: {}", err);
                     self.ui.push_app_event(AppEvent::Status(self.status.clone()));
                     self.ui.push_app_event(AppEvent::Log(format!("Pipeline error: {}", err)));
+                    // Only a dropped connection is something the user can fix themselves, so only
+                    // that variant gets the docker-compose hint as its own log line (its error
+                    // message carries the same hint).
+                    if matches!(err, crate::worker::WorkerError::Connection(_)) {
+                        self.ui.push_app_event(AppEvent::Log(
+                            "Hint: start the inference service with `cd python && docker-compose up`".into()
+                        ));
+                    }
                 }
                 WorkerResponse::Progress(p, ..) => {
                     self.ui.push_app_event(AppEvent::Progress(p));
@@ -171,6 +189,36 @@ impl AppState {
             }
         }
 
+        // Apply camera/generation commands issued by a running script
+        while let Ok(cmd) = self.script_cmd_rx.try_recv() {
+            match cmd {
+                ScriptCommand::SetPosition(pos) => {
+                    self.camera.position = pos.into();
+                }
+                ScriptCommand::LookAt(target) => {
+                    self.camera.target = target.into();
+                }
+                ScriptCommand::Orbit { yaw, pitch, radius } => {
+                    self.camera.azimuth = yaw;
+                    self.camera.elevation = pitch;
+                    self.camera.distance = radius;
+                    self.camera.update_position();
+                }
+                ScriptCommand::Generate { prompt, model } => {
+                    self.ui.push_app_event(AppEvent::Log(format!("script: generate({:?}, {:?})", prompt, model)));
+                    self.ui.push_ui_event(UiEvent::GenerateWithModel { prompt, model });
+                }
+                ScriptCommand::Capture { path } => {
+                    if let Some(ref cloud) = self.gaussian_cloud {
+                        match cloud.to_ply().and_then(|data| Ok(std::fs::write(&path, data)?)) {
+                            Ok(()) => self.ui.push_app_event(AppEvent::Log(format!("Captured to {}", path))),
+                            Err(e) => self.ui.push_app_event(AppEvent::Log(format!("Capture failed: {}", e))),
+                        }
+                    }
+                }
+            }
+        }
+
         let ui_events = self.ui.take_ui_events();
 
         for ev in ui_events {
@@ -265,6 +313,88 @@ impl AppState {
                 UiEvent::Log(msg) => {
                     self.ui.push_app_event(AppEvent::Log(format!("UI: {}", msg)));
                 }
+
+                UiEvent::RunScript(script) => {
+                    let ui_tx = self.ui.app_event_sender_clone();
+                    let script_cmd_tx = self.script_cmd_tx.clone();
+                    let window = self.window.clone();
+
+                    self.rt.spawn_blocking(move || {
+                        let (status_tx, status_rx) = std::sync::mpsc::channel::<String>();
+                        let runner = ScriptRunner::new(script_cmd_tx, status_tx);
+
+                        let _ = ui_tx.send(AppEvent::Status("Running script...".into()));
+
+                        if let Err(e) = runner.run(&script) {
+                            let _ = ui_tx.send(AppEvent::Log(format!("Script error: {}", e)));
+                        }
+
+                        while let Ok(message) = status_rx.try_recv() {
+                            let _ = ui_tx.send(AppEvent::Log(message));
+                        }
+
+                        let _ = ui_tx.send(AppEvent::Status("Script finished".into()));
+                        window.request_redraw();
+                    });
+                }
+
+                UiEvent::LoadScript(path) => {
+                    match std::fs::read_to_string(&path) {
+                        Ok(script) => {
+                            self.ui.push_ui_event(UiEvent::RunScript(script));
+                        }
+                        Err(e) => {
+                            self.ui.push_app_event(AppEvent::Log(format!("Failed to load script: {}", e)));
+                        }
+                    }
+                }
+
+                UiEvent::ExportTurntable { frames, width, height, out_dir } => {
+                    let Some(cloud) = self.gaussian_cloud.clone() else {
+                        self.ui.push_app_event(AppEvent::Status("No model to export".into()));
+                        continue;
+                    };
+
+                    let ui_tx = self.ui.app_event_sender_clone();
+                    let window = self.window.clone();
+                    let device = self.gfx.device.clone();
+                    let queue = self.gfx.queue.clone();
+                    let format = self.gfx.config.format;
+                    let mut camera = self.camera.clone();
+
+                    self.rt.spawn_blocking(move || {
+                        let _ = ui_tx.send(AppEvent::Status("Rendering turntable...".into()));
+
+                        std::fs::create_dir_all(&out_dir).ok();
+
+                        let mut renderer = pollster::block_on(
+                            GaussianRenderer::new(device, queue, format)
+                        );
+                        renderer.load_gaussians(&cloud);
+
+                        let start_azimuth = camera.azimuth;
+                        for i in 0..frames {
+                            camera.azimuth = start_azimuth + 360.0 * (i as f32) / (frames as f32);
+                            camera.update_position();
+
+                            let image = pollster::block_on(
+                                renderer.render_to_rgba(&camera, width, height)
+                            );
+
+                            let frame_path = std::path::Path::new(&out_dir)
+                                .join(format!("frame_{i:04}.png"));
+                            if let Err(e) = image.save(&frame_path) {
+                                let _ = ui_tx.send(AppEvent::Log(format!("Failed to write {:?}: {}", frame_path, e)));
+                            }
+
+                            let _ = ui_tx.send(AppEvent::Progress((i + 1) as f32 / frames as f32));
+                        }
+
+                        let _ = ui_tx.send(AppEvent::Status(format!("Turntable exported to {}", out_dir)));
+                        window.request_redraw();
+                    });
+                }
+
                 _ => {}
             }
         }
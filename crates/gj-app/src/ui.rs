@@ -57,6 +57,27 @@ pub fn draw_ui(ctx: &Context, state: &mut AppState) {
                     }
                 }
             }
+
+            if ui.button("🧊 Export Mesh").clicked() {
+                if let Some(path) = rfd::FileDialog::new()
+                    .add_filter("OBJ", &["obj"])
+                    .add_filter("glTF", &["gltf", "glb"])
+                    .save_file()
+                {
+                    let mesh = gj_core::mesh::extract_mesh(
+                        cloud,
+                        &gj_core::mesh::MeshExtractionConfig::default(),
+                    );
+                    match gj_core::mesh::save_mesh(&mesh, &path) {
+                        Ok(()) => {
+                            state.status = format!("Saved mesh to {:?}", path);
+                        }
+                        Err(e) => {
+                            state.status = format!("Mesh export error: {}", e);
+                        }
+                    }
+                }
+            }
         }
 
         ui.separator();
@@ -70,6 +91,32 @@ pub fn draw_ui(ctx: &Context, state: &mut AppState) {
             state.camera = gj_splat::camera::Camera::default();
             state.camera.aspect_ratio = state.size.width as f32 / state.size.height as f32;
         }
+
+        ui.separator();
+
+        ui.heading("☀ Exposure");
+        ui.add(egui::Slider::new(&mut state.renderer.exposure, 0.1..=8.0).text("exposure"));
+
+        ui.heading("🔍 Culling");
+        ui.add(egui::Slider::new(&mut state.renderer.min_pixel_radius, 0.0..=10.0).text("min splat radius (px)"));
+
+        egui::ComboBox::from_label("Tonemap")
+            .selected_text(match state.renderer.tonemap_mode {
+                gj_splat::renderer::TonemapMode::Reinhard => "Reinhard",
+                gj_splat::renderer::TonemapMode::AcesFilmic => "ACES Filmic",
+            })
+            .show_ui(ui, |ui| {
+                ui.selectable_value(
+                    &mut state.renderer.tonemap_mode,
+                    gj_splat::renderer::TonemapMode::Reinhard,
+                    "Reinhard",
+                );
+                ui.selectable_value(
+                    &mut state.renderer.tonemap_mode,
+                    gj_splat::renderer::TonemapMode::AcesFilmic,
+                    "ACES Filmic",
+                );
+            });
     });
 
     // Central 3D view is rendered by WGPU
@@ -1,46 +1,145 @@
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc::{channel, Sender, Receiver};
+use std::sync::{Arc, Mutex, Weak};
 use std::thread::{self, JoinHandle};
 use std::time::Duration;
 use image::RgbaImage;
 use gj_core::gaussian_cloud::GaussianCloud;
 use gj_core::Model3D;
+use reqwest::StatusCode;
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::job_store::{JobRecord, JobStore};
+use crate::notifier::{Notifier, NotifierConfig};
+
+/// Failure modes of the job-submission/polling/streaming pipeline, modeled on
+/// [`gj_core::error::Error`] - a typed variant per category instead of a bag of `format!`-ed
+/// strings, so callers (the UI) can match on category and react (retry, prompt to start the
+/// service, etc.) rather than pattern-matching message text.
+#[derive(Error, Debug)]
+pub enum WorkerError {
+    #[error("Failed to connect to inference service: {0}. Make sure the FastAPI service is running (cd python && docker-compose up)")]
+    Connection(String),
+
+    #[error("Inference service returned an error status: {0}")]
+    ServiceStatus(StatusCode),
+
+    #[error("Job {job_id} failed: {message}")]
+    JobFailed { job_id: String, message: String },
+
+    #[error("Failed to parse response from inference service: {0}")]
+    Parse(String),
+
+    #[error("Failed to load generated .ply: {0}")]
+    PlyLoad(String),
+
+    #[error("Failed to read or write job store: {0}")]
+    Storage(String),
+
+    #[error("{0}")]
+    Other(String),
+}
+
+/// Default location of the job store, alongside the generated PLYs in `outputs/`.
+const JOB_STORE_PATH: &str = "outputs/jobs.db";
+
+/// Fallback polling cadence when a job has no SSE/chunked stream endpoint to attach to. Only
+/// used by [`poll_job_status`] - [`stream_job_status`] pushes updates as the service emits them.
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(2);
 
 pub enum WorkerCommand {
     GenerateFromImages(Vec<RgbaImage>),
     GenerateFromPrompt { prompt: String, model: Model3D },
     CheckStatus(String), // Check job status by ID
+    CancelJob(String),
     Shutdown,
 }
 
 pub enum WorkerResponse {
     Success(GaussianCloud),
-    Error(String),
-    Progress(f32),
+    Error(WorkerError),
+    Progress(f32, String), // percentage, job ID
     Status(String),
     JobSubmitted(String), // Job ID
 }
 
+/// Cancellation flag for one submitted job's poller thread. The poller thread itself owns the
+/// strong `Arc<JobHandle>`; `ACTIVE_JOBS` only keeps a `Weak` to it, so an entry naturally goes
+/// stale once its poller finishes, without the main loop having to track completion explicitly.
+/// Mirrors build-o-tron's driver, which holds weak handles to running tasks in a
+/// `Mutex<HashMap<u64, Weak<...>>>`.
+struct JobHandle {
+    cancelled: AtomicBool,
+}
+
+type ActiveJobs = Arc<Mutex<HashMap<String, Weak<JobHandle>>>>;
+
 pub struct InferenceWorker {
     pub(crate) command_tx: Sender<WorkerCommand>,
     pub(crate) response_rx: Receiver<WorkerResponse>,
     thread_handle: Option<JoinHandle<()>>,
+    store: JobStore,
 }
 
 impl InferenceWorker {
     pub fn new() -> Self {
+        Self::with_poll_interval(DEFAULT_POLL_INTERVAL)
+    }
+
+    /// Like [`Self::new`], but overrides the polling cadence used when a job has no stream
+    /// endpoint to attach to - for backends known to be slow, where 2-second polling just adds
+    /// pointless request load.
+    pub fn with_poll_interval(poll_interval: Duration) -> Self {
+        Self::with_config(poll_interval, NotifierConfig::default())
+    }
+
+    /// Like [`Self::with_poll_interval`], but also configures which sinks fire a completion
+    /// notification when a job reaches `SUCCESS` or `FAILURE` - see [`NotifierConfig`].
+    pub fn with_config(poll_interval: Duration, notifier_config: NotifierConfig) -> Self {
+        let store = JobStore::open(JOB_STORE_PATH)
+            .unwrap_or_else(|e| panic!("Failed to open job store at {}: {}", JOB_STORE_PATH, e));
+        let notifier = Notifier::new(notifier_config);
+
         let (cmd_tx, cmd_rx) = channel::<WorkerCommand>();
         let (resp_tx, resp_rx) = channel::<WorkerResponse>();
 
+        let worker_store = store.clone();
         let thread_handle = thread::spawn(move || {
-            // Worker loop
+            let active_jobs: ActiveJobs = Arc::new(Mutex::new(HashMap::new()));
+            // Poller threads spawned by this loop, joined on shutdown so we don't exit while one
+            // is still mid-flight. Finished pollers join instantly, so this never blocks on a
+            // job that's already done.
+            let mut pollers: Vec<JoinHandle<()>> = Vec::new();
+
+            // Jobs that were still PENDING/STARTED/RETRY when the app last exited get a poller
+            // re-attached immediately, so a restart doesn't silently abandon them.
+            match worker_store.resumable_job_ids() {
+                Ok(job_ids) => {
+                    for job_id in job_ids {
+                        let _ = resp_tx.send(WorkerResponse::Status(format!("Resuming job {}...", job_id)));
+                        pollers.push(spawn_poller(job_id, active_jobs.clone(), resp_tx.clone(), worker_store.clone(), poll_interval, notifier.clone()));
+                    }
+                }
+                Err(e) => {
+                    let _ = resp_tx.send(WorkerResponse::Error(WorkerError::Storage(e)));
+                }
+            }
+
+            // Main loop only dispatches commands and spawns/cancels per-job pollers; it never
+            // blocks on a job's own HTTP polling, so it keeps servicing new commands (including
+            // Shutdown) while other jobs are still running.
             loop {
                 match cmd_rx.recv() {
                     Ok(WorkerCommand::GenerateFromImages(images)) => {
                         let _ = resp_tx.send(WorkerResponse::Status("Processing images...".into()));
-                        let _ = resp_tx.send(WorkerResponse::Error(
+                        let _ = resp_tx.send(WorkerResponse::Error(WorkerError::Other(
                             "Image-based generation not yet implemented with Shap-E. Use text prompts instead.".into()
-                        ));
+                        )));
+                        let _ = images;
                     }
 
                     Ok(WorkerCommand::GenerateFromPrompt { prompt, model }) => {
@@ -48,35 +147,60 @@ impl InferenceWorker {
                             format!("Submitting job to {} service...", model.name())
                         ));
 
-                        // Submit job and get job ID
-                        match submit_generation_job(&prompt, model) {
-                            Ok(job_id) => {
+                        let model_id = model.id().to_string();
+
+                        match submit_generation_job(&prompt, model, &resp_tx) {
+                            Ok((job_id, guidance_scale, num_inference_steps)) => {
+                                if let Err(e) = worker_store.insert_job(&job_id, &prompt, &model_id, guidance_scale, num_inference_steps) {
+                                    let _ = resp_tx.send(WorkerResponse::Error(WorkerError::Storage(e)));
+                                }
+
                                 let _ = resp_tx.send(WorkerResponse::JobSubmitted(job_id.clone()));
                                 let _ = resp_tx.send(WorkerResponse::Status(
                                     format!("Job submitted (ID: {})", job_id)
                                 ));
 
-                                // Poll for status
-                                if let Err(e) = poll_job_status(&job_id, &resp_tx) {
-                                    let _ = resp_tx.send(WorkerResponse::Error(
-                                        format!("Failed to poll job: {}", e)
-                                    ));
-                                }
+                                pollers.push(spawn_poller(job_id, active_jobs.clone(), resp_tx.clone(), worker_store.clone(), poll_interval, notifier.clone()));
                             }
                             Err(e) => {
-                                let _ = resp_tx.send(WorkerResponse::Error(
-                                    format!("Failed to submit job: {}", e)
-                                ));
+                                let _ = resp_tx.send(WorkerResponse::Error(e));
                             }
                         }
                     }
 
                     Ok(WorkerCommand::CheckStatus(job_id)) => {
-                        if let Err(e) = poll_job_status(&job_id, &resp_tx) {
-                            let _ = resp_tx.send(WorkerResponse::Error(
-                                format!("Failed to check status: {}", e)
-                            ));
+                        // Already has a poller running for it (weak ref still upgrades) - the
+                        // next status it sees will be reported over response_rx as normal.
+                        let already_tracked = active_jobs
+                            .lock()
+                            .unwrap()
+                            .get(&job_id)
+                            .and_then(Weak::upgrade)
+                            .is_some();
+
+                        if !already_tracked {
+                            pollers.push(spawn_poller(job_id, active_jobs.clone(), resp_tx.clone(), worker_store.clone(), poll_interval, notifier.clone()));
+                        }
+                    }
+
+                    Ok(WorkerCommand::CancelJob(job_id)) => {
+                        let handle = active_jobs.lock().unwrap().remove(&job_id).and_then(|w| w.upgrade());
+
+                        if let Some(handle) = handle {
+                            handle.cancelled.store(true, Ordering::Relaxed);
                         }
+
+                        if let Err(e) = worker_store.fail_job(&job_id, "CANCELLED", "Cancelled by user") {
+                            let _ = resp_tx.send(WorkerResponse::Error(WorkerError::Storage(e)));
+                        }
+
+                        let _ = resp_tx.send(WorkerResponse::Status(format!("Cancelling job {}...", job_id)));
+
+                        // Best-effort - the poller thread tears itself down as soon as it next
+                        // checks `cancelled`, so the DELETE is fire-and-forget from here.
+                        thread::spawn(move || {
+                            let _ = cancel_generation_job(&job_id);
+                        });
                     }
 
                     Ok(WorkerCommand::Shutdown) => {
@@ -88,15 +212,26 @@ impl InferenceWorker {
                     }
                 }
             }
+
+            for poller in pollers {
+                let _ = poller.join();
+            }
         });
 
         Self {
             command_tx: cmd_tx,
             response_rx: resp_rx,
             thread_handle: Some(thread_handle),
+            store,
         }
     }
 
+    /// Full job history (including jobs from previous runs), most recent first, so the UI can
+    /// show past generations and reload their PLYs without re-running inference.
+    pub fn list_jobs(&self) -> Result<Vec<JobRecord>, String> {
+        self.store.list_jobs()
+    }
+
     pub fn send_images(&self, images: Vec<RgbaImage>) -> Result<(), String> {
         self.command_tx
             .send(WorkerCommand::GenerateFromImages(images))
@@ -109,6 +244,12 @@ impl InferenceWorker {
             .map_err(|e| format!("Failed to send prompt to worker: {}", e))
     }
 
+    pub fn cancel_job(&self, job_id: String) -> Result<(), String> {
+        self.command_tx
+            .send(WorkerCommand::CancelJob(job_id))
+            .map_err(|e| format!("Failed to send cancel to worker: {}", e))
+    }
+
     pub fn try_recv_response(&self) -> Option<WorkerResponse> {
         self.response_rx.try_recv().ok()
     }
@@ -127,6 +268,39 @@ impl Drop for InferenceWorker {
     }
 }
 
+/// Spawn a poller thread for `job_id`, registering a weak handle in `active_jobs` so
+/// `CancelJob`/`CheckStatus` can find it by ID. Returns the poller's `JoinHandle` so the caller
+/// can join it on shutdown. Tries the streaming transport first and only falls back to
+/// fixed-interval polling if the service has no stream endpoint for this job.
+fn spawn_poller(
+    job_id: String,
+    active_jobs: ActiveJobs,
+    resp_tx: Sender<WorkerResponse>,
+    store: JobStore,
+    poll_interval: Duration,
+    notifier: Notifier,
+) -> JoinHandle<()> {
+    let handle = Arc::new(JobHandle { cancelled: AtomicBool::new(false) });
+    active_jobs.lock().unwrap().insert(job_id.clone(), Arc::downgrade(&handle));
+
+    thread::spawn(move || {
+        match stream_job_status(&job_id, &handle, &resp_tx, &store, &notifier) {
+            Ok(true) => {} // ran to completion (or cancellation) over the stream
+            Ok(false) => {
+                // No stream endpoint for this job - fall back to polling.
+                if let Err(e) = poll_job_status(&job_id, &handle, &resp_tx, &store, poll_interval, &notifier) {
+                    let _ = resp_tx.send(WorkerResponse::Error(e));
+                }
+            }
+            Err(e) => {
+                let _ = resp_tx.send(WorkerResponse::Error(e));
+            }
+        }
+
+        active_jobs.lock().unwrap().remove(&job_id);
+    })
+}
+
 // ============================================================================
 // API Client
 // ============================================================================
@@ -162,111 +336,325 @@ struct JobResult {
     prompt: String,
 }
 
-/// Submit generation job and return job ID
-fn submit_generation_job(prompt: &str, model: Model3D) -> Result<String, String> {
+/// Default generation parameters used for every submitted job - also what's persisted alongside
+/// the job ID so a resumed/re-listed job records the request that produced it.
+const DEFAULT_GUIDANCE_SCALE: f32 = 15.0;
+const DEFAULT_NUM_INFERENCE_STEPS: usize = 64;
+
+/// Backoff schedule for transient API-client failures: doubles from `INITIAL_RETRY_BACKOFF` up to
+/// `MAX_RETRY_BACKOFF`, giving up after `MAX_RETRY_ATTEMPTS` - a dropped connection or a 5xx
+/// shouldn't kill a long-running job over one bad request.
+const INITIAL_RETRY_BACKOFF: Duration = Duration::from_secs(1);
+const MAX_RETRY_BACKOFF: Duration = Duration::from_secs(30);
+const MAX_RETRY_ATTEMPTS: u32 = 5;
+
+/// Whether `err` is worth retrying: a dropped connection or a 5xx is very plausibly transient; a
+/// 4xx or a malformed response is not going to fix itself on the next attempt.
+fn is_retryable(err: &WorkerError) -> bool {
+    match err {
+        WorkerError::Connection(_) => true,
+        WorkerError::ServiceStatus(status) => status.is_server_error(),
+        _ => false,
+    }
+}
+
+/// Run `op` with exponential backoff on [`is_retryable`] failures, reporting each retry over
+/// `resp_tx` so the user sees a recovery attempt in progress instead of a silent stall. Surfaces
+/// the error only once the attempt budget is exhausted (or immediately, for a non-retryable one).
+fn retry_with_backoff<T>(
+    resp_tx: &Sender<WorkerResponse>,
+    mut op: impl FnMut() -> Result<T, WorkerError>,
+) -> Result<T, WorkerError> {
+    let mut delay = INITIAL_RETRY_BACKOFF;
+
+    for attempt in 1..=MAX_RETRY_ATTEMPTS {
+        match op() {
+            Ok(value) => return Ok(value),
+            Err(e) if is_retryable(&e) && attempt < MAX_RETRY_ATTEMPTS => {
+                let _ = resp_tx.send(WorkerResponse::Status(format!(
+                    "{} - retrying in {}s ({}/{})",
+                    e, delay.as_secs(), attempt, MAX_RETRY_ATTEMPTS
+                )));
+                thread::sleep(delay);
+                delay = (delay * 2).min(MAX_RETRY_BACKOFF);
+            }
+            Err(e) => return Err(e),
+        }
+    }
+
+    unreachable!("loop always returns by the final attempt")
+}
+
+/// Submit generation job and return its ID plus the guidance/step parameters used, for the
+/// caller to persist alongside it.
+fn submit_generation_job(prompt: &str, model: Model3D, resp_tx: &Sender<WorkerResponse>) -> Result<(String, f32, usize), WorkerError> {
     let client = reqwest::blocking::Client::new();
     let url = "http://127.0.0.1:5000/generate";
 
     let request_body = GenerateRequest {
         prompt: prompt.to_string(),
         model: model.id().to_string(),
-        guidance_scale: 15.0,
-        num_inference_steps: 64,
+        guidance_scale: DEFAULT_GUIDANCE_SCALE,
+        num_inference_steps: DEFAULT_NUM_INFERENCE_STEPS,
     };
 
+    let result: JobResponse = retry_with_backoff(resp_tx, || {
+        let response = client
+            .post(url)
+            .json(&request_body)
+            .send()
+            .map_err(|e| WorkerError::Connection(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(WorkerError::ServiceStatus(response.status()));
+        }
+
+        response.json().map_err(|e| WorkerError::Parse(e.to_string()))
+    })?;
+
+    Ok((result.job_id, DEFAULT_GUIDANCE_SCALE, DEFAULT_NUM_INFERENCE_STEPS))
+}
+
+/// Ask the service to drop a job (best-effort - the poller stops reporting on it regardless).
+fn cancel_generation_job(job_id: &str) -> Result<(), WorkerError> {
+    let client = reqwest::blocking::Client::new();
+    let url = format!("http://127.0.0.1:5000/status/{}", job_id);
+
     let response = client
-        .post(url)
-        .json(&request_body)
+        .delete(&url)
         .send()
-        .map_err(|e| format!("Failed to connect: {}. Make sure FastAPI service is running (cd python && docker-compose up)", e))?;
+        .map_err(|e| WorkerError::Connection(e.to_string()))?;
 
     if !response.status().is_success() {
-        return Err(format!("Service returned error: {}", response.status()));
+        return Err(WorkerError::ServiceStatus(response.status()));
     }
 
-    let result: JobResponse = response
-        .json()
-        .map_err(|e| format!("Failed to parse response: {}", e))?;
-
-    Ok(result.job_id)
+    Ok(())
 }
 
-/// Poll job status until complete or failed
-fn poll_job_status(job_id: &str, resp_tx: &Sender<WorkerResponse>) -> Result<(), String> {
+/// Chunk size used when streaming a remote artifact to a temp file.
+const DOWNLOAD_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Resolve a job result's `output_path` into a local path `GaussianCloud::from_ply` can open.
+/// If the service reports a local filesystem path (the common case when it shares a volume with
+/// this process), that path is returned as-is. If it's a `http(s)://` URL - the case for a
+/// dockerized or remote service with no shared filesystem - the artifact is streamed to a temp
+/// file, reporting byte-level download progress over `resp_tx` as it goes.
+fn fetch_artifact(output_path: &str, job_id: &str, resp_tx: &Sender<WorkerResponse>) -> Result<PathBuf, WorkerError> {
+    if !output_path.starts_with("http://") && !output_path.starts_with("https://") {
+        return Ok(PathBuf::from(output_path));
+    }
+
+    let _ = resp_tx.send(WorkerResponse::Status("Downloading generated .ply...".into()));
+
     let client = reqwest::blocking::Client::new();
-    let url = format!("http://127.0.0.1:5000/status/{}", job_id);
+    let mut response = client
+        .get(output_path)
+        .send()
+        .map_err(|e| WorkerError::Connection(e.to_string()))?;
 
-    let mut last_progress = 0.0;
+    if !response.status().is_success() {
+        return Err(WorkerError::ServiceStatus(response.status()));
+    }
+
+    let total_bytes = response.content_length();
+    let dest = std::env::temp_dir().join(format!("gj-{}.ply", job_id));
+    let mut file = std::fs::File::create(&dest)
+        .map_err(|e| WorkerError::Other(format!("Failed to create temp file {}: {}", dest.display(), e)))?;
+
+    let mut downloaded: u64 = 0;
+    let mut buf = [0u8; DOWNLOAD_CHUNK_SIZE];
 
     loop {
-        thread::sleep(Duration::from_secs(2)); // Poll every 2 seconds
+        let read = response.read(&mut buf).map_err(|e| WorkerError::Connection(e.to_string()))?;
+        if read == 0 {
+            break;
+        }
 
-        let response = client
-            .get(&url)
-            .send()
-            .map_err(|e| format!("Failed to check status: {}", e))?;
+        file.write_all(&buf[..read])
+            .map_err(|e| WorkerError::Other(format!("Failed to write temp file {}: {}", dest.display(), e)))?;
+        downloaded += read as u64;
 
-        if !response.status().is_success() {
-            return Err(format!("Status check failed: {}", response.status()));
+        if let Some(total_bytes) = total_bytes.filter(|&t| t > 0) {
+            let fraction = (downloaded as f32 / total_bytes as f32).min(1.0);
+            let _ = resp_tx.send(WorkerResponse::Progress(fraction, job_id.to_string()));
         }
+    }
 
-        let status: JobStatusResponse = response
-            .json()
-            .map_err(|e| format!("Failed to parse status: {}", e))?;
+    Ok(dest)
+}
 
-        // Update progress if changed
-        if let Some(progress) = status.progress {
-            if progress != last_progress {
-                let _ = resp_tx.send(WorkerResponse::Progress(progress));
-                last_progress = progress;
-            }
+/// Whether a single status update concluded the job, and if so how.
+enum UpdateOutcome {
+    /// Job is still PENDING/STARTED/RETRY - keep polling/streaming.
+    Continue,
+    Done(Result<(), WorkerError>),
+}
+
+/// Apply one `JobStatusResponse` observed from either transport: forward progress/message over
+/// `resp_tx`, persist state transitions into `store`, and load the PLY on success. Shared by
+/// [`poll_job_status`] and [`stream_job_status`] so the two transports can't drift in behavior.
+fn apply_status_update(
+    job_id: &str,
+    status: JobStatusResponse,
+    last_progress: &mut f32,
+    last_status: &mut String,
+    resp_tx: &Sender<WorkerResponse>,
+    store: &JobStore,
+    notifier: &Notifier,
+) -> UpdateOutcome {
+    if let Some(progress) = status.progress {
+        if progress != *last_progress {
+            let _ = resp_tx.send(WorkerResponse::Progress(progress, job_id.to_string()));
+            *last_progress = progress;
         }
+    }
+
+    if let Some(ref message) = status.message {
+        let _ = resp_tx.send(WorkerResponse::Status(message.clone()));
+    }
 
-        // Update status message
-        if let Some(ref message) = status.message {
-            let _ = resp_tx.send(WorkerResponse::Status(message.clone()));
+    if status.status != *last_status {
+        if let Err(e) = store.update_status(job_id, &status.status) {
+            let _ = resp_tx.send(WorkerResponse::Error(WorkerError::Storage(e)));
         }
+        *last_status = status.status.clone();
+    }
 
-        match status.status.as_str() {
-            "SUCCESS" => {
-                if let Some(result) = status.result {
-                    let _ = resp_tx.send(WorkerResponse::Status(
-                        "Loading generated Gaussians...".into()
-                    ));
-
-                    // Load the PLY file
-                    match gj_core::gaussian_cloud::GaussianCloud::from_ply(&result.output_path) {
-                        Ok(cloud) => {
-                            let _ = resp_tx.send(WorkerResponse::Status(
-                                format!("Loaded {} Gaussians", cloud.count)
-                            ));
-                            let _ = resp_tx.send(WorkerResponse::Success(cloud));
-                            return Ok(());
-                        }
-                        Err(e) => {
-                            return Err(format!("Failed to load .ply: {}", e));
-                        }
+    match status.status.as_str() {
+        "SUCCESS" => {
+            let Some(result) = status.result else {
+                return UpdateOutcome::Done(Err(WorkerError::Other(
+                    "Job succeeded but no result path returned".into()
+                )));
+            };
+
+            let ply_path = match fetch_artifact(&result.output_path, job_id, resp_tx) {
+                Ok(path) => path,
+                Err(e) => return UpdateOutcome::Done(Err(e)),
+            };
+
+            let _ = resp_tx.send(WorkerResponse::Status("Loading generated Gaussians...".into()));
+
+            match gj_core::gaussian_cloud::GaussianCloud::from_ply(&ply_path) {
+                Ok(cloud) => {
+                    if let Err(e) = store.complete_job(job_id, &result.output_path) {
+                        let _ = resp_tx.send(WorkerResponse::Error(WorkerError::Storage(e)));
                     }
-                } else {
-                    return Err("Job succeeded but no result path returned".into());
+                    let _ = resp_tx.send(WorkerResponse::Status(format!("Loaded {} Gaussians", cloud.count)));
+                    notifier.notify_completion(store, job_id, Some(&result.output_path), None);
+                    let _ = resp_tx.send(WorkerResponse::Success(cloud));
+                    UpdateOutcome::Done(Ok(()))
                 }
+                Err(e) => UpdateOutcome::Done(Err(WorkerError::PlyLoad(e.to_string()))),
             }
+        }
 
-            "FAILURE" => {
-                let error_msg = status.error.unwrap_or_else(|| "Unknown error".into());
-                let _ = resp_tx.send(WorkerResponse::Error(error_msg.clone()));
-                return Err(error_msg);
+        "FAILURE" => {
+            let message = status.error.unwrap_or_else(|| "Unknown error".into());
+            if let Err(e) = store.fail_job(job_id, "FAILURE", &message) {
+                let _ = resp_tx.send(WorkerResponse::Error(WorkerError::Storage(e)));
             }
+            notifier.notify_completion(store, job_id, None, Some(&message));
+            UpdateOutcome::Done(Err(WorkerError::JobFailed { job_id: job_id.to_string(), message }))
+        }
 
-            "PENDING" | "STARTED" | "RETRY" => {
-                // Continue polling
-                continue;
-            }
+        // "PENDING" | "STARTED" | "RETRY" and anything else unrecognized - keep going.
+        _ => UpdateOutcome::Continue,
+    }
+}
 
-            _ => {
-                // Unknown status, continue polling
-                continue;
+/// Poll job status at `poll_interval` until complete, failed, or cancelled via `handle`,
+/// persisting each state transition into `store` as it's observed. The fallback transport for
+/// services with no stream endpoint - see [`stream_job_status`] for the preferred path.
+fn poll_job_status(job_id: &str, handle: &JobHandle, resp_tx: &Sender<WorkerResponse>, store: &JobStore, poll_interval: Duration, notifier: &Notifier) -> Result<(), WorkerError> {
+    let client = reqwest::blocking::Client::new();
+    let url = format!("http://127.0.0.1:5000/status/{}", job_id);
+
+    let mut last_progress = 0.0;
+    let mut last_status = String::new();
+    // Widened instead of `poll_interval` while the service itself reports `RETRY`, so the client
+    // backs off in step with a service that's already asked us to slow down.
+    let mut next_delay = poll_interval;
+
+    loop {
+        thread::sleep(next_delay);
+
+        if handle.cancelled.load(Ordering::Relaxed) {
+            let _ = resp_tx.send(WorkerResponse::Status(format!("Job {} cancelled", job_id)));
+            return Ok(());
+        }
+
+        let status: JobStatusResponse = retry_with_backoff(resp_tx, || {
+            let response = client
+                .get(&url)
+                .send()
+                .map_err(|e| WorkerError::Connection(e.to_string()))?;
+
+            if !response.status().is_success() {
+                return Err(WorkerError::ServiceStatus(response.status()));
             }
+
+            response.json().map_err(|e| WorkerError::Parse(e.to_string()))
+        })?;
+
+        next_delay = if status.status == "RETRY" {
+            (next_delay * 2).min(MAX_RETRY_BACKOFF)
+        } else {
+            poll_interval
+        };
+
+        match apply_status_update(job_id, status, &mut last_progress, &mut last_status, resp_tx, store, notifier) {
+            UpdateOutcome::Continue => continue,
+            UpdateOutcome::Done(result) => return result,
         }
     }
-}
\ No newline at end of file
+}
+
+/// Attach to the service's long-lived status stream for `job_id` and forward each decoded update
+/// as it arrives, instead of polling on a fixed interval. The stream is newline-delimited JSON
+/// (optionally SSE-style `data: {...}` lines), each payload shaped like [`JobStatusResponse`].
+///
+/// Returns `Ok(true)` if the job succeeded (or was cancelled) over the stream, `Ok(false)` if the
+/// service has no stream endpoint for this job (the caller should fall back to
+/// [`poll_job_status`]), and `Err` if the job failed, the service could not be reached or answered
+/// with an error status, or the stream broke or sent something unparseable - mirrors build-o-tron's driver, which reads its task stream via
+/// `StreamBody`/`ReceiverStream` rather than re-fetching state on a timer.
+fn stream_job_status(job_id: &str, handle: &JobHandle, resp_tx: &Sender<WorkerResponse>, store: &JobStore, notifier: &Notifier) -> Result<bool, WorkerError> {
+    let client = reqwest::blocking::Client::new();
+    let url = format!("http://127.0.0.1:5000/status/{}/stream", job_id);
+
+    let response = match client.get(&url).send() {
+        Ok(response) if response.status() == StatusCode::NOT_FOUND => return Ok(false),
+        Ok(response) if response.status().is_success() => response,
+        Ok(response) => return Err(WorkerError::ServiceStatus(response.status())),
+        Err(e) => return Err(WorkerError::Connection(e.to_string())),
+    };
+
+    let mut last_progress = 0.0;
+    let mut last_status = String::new();
+    let reader = BufReader::new(response);
+
+    for line in reader.lines() {
+        if handle.cancelled.load(Ordering::Relaxed) {
+            let _ = resp_tx.send(WorkerResponse::Status(format!("Job {} cancelled", job_id)));
+            return Ok(true);
+        }
+
+        let line = line.map_err(|e| WorkerError::Connection(e.to_string()))?;
+        let payload = line.trim().strip_prefix("data: ").unwrap_or(line.trim());
+        if payload.is_empty() {
+            continue;
+        }
+
+        let status: JobStatusResponse = serde_json::from_str(payload)
+            .map_err(|e| WorkerError::Parse(e.to_string()))?;
+
+        match apply_status_update(job_id, status, &mut last_progress, &mut last_status, resp_tx, store, notifier) {
+            UpdateOutcome::Continue => continue,
+            UpdateOutcome::Done(result) => return result.map(|()| true),
+        }
+    }
+
+    Err(WorkerError::Other("Stream ended without a final status".into()))
+}
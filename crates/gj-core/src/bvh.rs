@@ -0,0 +1,245 @@
+//! Bounding-volume hierarchy over a [`GaussianCloud`]'s Gaussians, for spatial queries (picking,
+//! box selection) that would otherwise be an O(n) scan. Built top-down, splitting the longest
+//! axis of each node's AABB at the median centroid, down to a leaf threshold.
+
+use crate::bounding_box::BoundingBox;
+use crate::gaussian_cloud::GaussianCloud;
+
+/// Stop splitting once a node holds this many Gaussians or fewer.
+const LEAF_THRESHOLD: usize = 16;
+
+struct BvhNode {
+    bounds: BoundingBox,
+    /// `Some((left, right))` for an internal node; `None` for a leaf, where `start..start+count`
+    /// indexes into [`Bvh::indices`] instead.
+    children: Option<(usize, usize)>,
+    start: usize,
+    count: usize,
+}
+
+/// A built BVH over a cloud's Gaussian centers. Holds its own copy of positions/scales so
+/// [`Bvh::raycast`] and [`Bvh::query_box`] don't need the original [`GaussianCloud`] passed back
+/// in; rebuild with [`GaussianCloud::build_bvh`] if the cloud changes.
+pub struct Bvh {
+    nodes: Vec<BvhNode>,
+    indices: Vec<u32>,
+    positions: Vec<[f32; 3]>,
+    scales: Vec<[f32; 3]>,
+}
+
+fn node_bounds(positions: &[[f32; 3]], scales: &[[f32; 3]], members: &[u32]) -> BoundingBox {
+    let mut min = [f32::INFINITY; 3];
+    let mut max = [f32::NEG_INFINITY; 3];
+
+    for &i in members {
+        let p = positions[i as usize];
+        let s = scales[i as usize];
+        for axis in 0..3 {
+            // Inflate by 3 standard deviations so the AABB fully contains the Gaussian's
+            // ellipsoid, not just its center.
+            let radius = 3.0 * s[axis];
+            min[axis] = min[axis].min(p[axis] - radius);
+            max[axis] = max[axis].max(p[axis] + radius);
+        }
+    }
+
+    BoundingBox { min, max }
+}
+
+fn aabb_overlaps(a: &BoundingBox, b: &BoundingBox) -> bool {
+    (0..3).all(|axis| a.min[axis] <= b.max[axis] && a.max[axis] >= b.min[axis])
+}
+
+/// Slab test: the entry distance along `dir` (clamped to 0) where the ray first enters `bounds`,
+/// or `None` if it misses. Assumes `dir` need not be normalized.
+fn slab_test(bounds: &BoundingBox, origin: [f32; 3], dir: [f32; 3]) -> Option<f32> {
+    let mut t_min = f32::NEG_INFINITY;
+    let mut t_max = f32::INFINITY;
+
+    for axis in 0..3 {
+        if dir[axis].abs() < 1e-8 {
+            if origin[axis] < bounds.min[axis] || origin[axis] > bounds.max[axis] {
+                return None;
+            }
+            continue;
+        }
+
+        let inv_d = 1.0 / dir[axis];
+        let mut t0 = (bounds.min[axis] - origin[axis]) * inv_d;
+        let mut t1 = (bounds.max[axis] - origin[axis]) * inv_d;
+        if t0 > t1 {
+            std::mem::swap(&mut t0, &mut t1);
+        }
+        t_min = t_min.max(t0);
+        t_max = t_max.min(t1);
+        if t_min > t_max {
+            return None;
+        }
+    }
+
+    if t_max < 0.0 {
+        return None;
+    }
+    Some(t_min.max(0.0))
+}
+
+/// Nearest intersection of a ray with a sphere of `radius` centered at `center`, or `None`.
+/// Assumes `dir` is a unit vector.
+fn ray_sphere_hit(origin: [f32; 3], dir: [f32; 3], center: [f32; 3], radius: f32) -> Option<f32> {
+    let oc = [origin[0] - center[0], origin[1] - center[1], origin[2] - center[2]];
+    let b = oc[0] * dir[0] + oc[1] * dir[1] + oc[2] * dir[2];
+    let c = oc[0] * oc[0] + oc[1] * oc[1] + oc[2] * oc[2] - radius * radius;
+    let discriminant = b * b - c;
+    if discriminant < 0.0 {
+        return None;
+    }
+
+    let t = -b - discriminant.sqrt();
+    if t < 0.0 { None } else { Some(t) }
+}
+
+impl Bvh {
+    fn build(cloud: &GaussianCloud) -> Self {
+        let mut indices: Vec<u32> = (0..cloud.count as u32).collect();
+        let mut nodes = Vec::new();
+
+        if cloud.count > 0 {
+            Self::build_node(&cloud.positions, &cloud.scales, &mut indices, 0, cloud.count, &mut nodes);
+        }
+
+        Bvh {
+            nodes,
+            indices,
+            positions: cloud.positions.clone(),
+            scales: cloud.scales.clone(),
+        }
+    }
+
+    /// Build the subtree over `indices[start..start + count]`, returning its node's index.
+    fn build_node(
+        positions: &[[f32; 3]],
+        scales: &[[f32; 3]],
+        indices: &mut [u32],
+        start: usize,
+        count: usize,
+        nodes: &mut Vec<BvhNode>,
+    ) -> usize {
+        let bounds = node_bounds(positions, scales, &indices[start..start + count]);
+        let node_index = nodes.len();
+        nodes.push(BvhNode { bounds, children: None, start, count });
+
+        if count <= LEAF_THRESHOLD {
+            return node_index;
+        }
+
+        let extent = [
+            nodes[node_index].bounds.max[0] - nodes[node_index].bounds.min[0],
+            nodes[node_index].bounds.max[1] - nodes[node_index].bounds.min[1],
+            nodes[node_index].bounds.max[2] - nodes[node_index].bounds.min[2],
+        ];
+        let axis = (0..3usize)
+            .max_by(|&a, &b| extent[a].partial_cmp(&extent[b]).unwrap())
+            .unwrap();
+
+        indices[start..start + count]
+            .sort_by(|&a, &b| positions[a as usize][axis].partial_cmp(&positions[b as usize][axis]).unwrap());
+
+        let mid = count / 2;
+        let left = Self::build_node(positions, scales, indices, start, mid, nodes);
+        let right = Self::build_node(positions, scales, indices, start + mid, count - mid, nodes);
+
+        nodes[node_index].children = Some((left, right));
+        node_index
+    }
+
+    /// Nearest Gaussian hit by the ray from `origin` in (unit) direction `dir`, approximating
+    /// each Gaussian as a sphere of radius `3 * max(scale)`.
+    pub fn raycast(&self, origin: [f32; 3], dir: [f32; 3]) -> Option<u32> {
+        if self.nodes.is_empty() {
+            return None;
+        }
+
+        let mut best: Option<(f32, u32)> = None;
+        self.raycast_node(0, origin, dir, &mut best);
+        best.map(|(_, index)| index)
+    }
+
+    fn raycast_node(&self, node_index: usize, origin: [f32; 3], dir: [f32; 3], best: &mut Option<(f32, u32)>) {
+        let node = &self.nodes[node_index];
+        let Some(t_enter) = slab_test(&node.bounds, origin, dir) else { return };
+        if let Some((best_t, _)) = best {
+            if t_enter > *best_t {
+                return;
+            }
+        }
+
+        match node.children {
+            Some((left, right)) => {
+                let t_left = slab_test(&self.nodes[left].bounds, origin, dir);
+                let t_right = slab_test(&self.nodes[right].bounds, origin, dir);
+                // Descend whichever child the ray enters first, so a hit there can prune the
+                // other subtree via the `t_enter` check above.
+                let (first, second) = match (t_left, t_right) {
+                    (Some(tl), Some(tr)) if tr < tl => (right, left),
+                    _ => (left, right),
+                };
+                self.raycast_node(first, origin, dir, best);
+                self.raycast_node(second, origin, dir, best);
+            }
+            None => {
+                for k in node.start..node.start + node.count {
+                    let index = self.indices[k];
+                    let center = self.positions[index as usize];
+                    let scale = self.scales[index as usize];
+                    let radius = 3.0 * scale[0].max(scale[1]).max(scale[2]);
+
+                    if let Some(t) = ray_sphere_hit(origin, dir, center, radius) {
+                        if best.is_none_or(|(best_t, _)| t < best_t) {
+                            *best = Some((t, index));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Indices of Gaussians whose center falls inside `region`.
+    pub fn query_box(&self, region: &BoundingBox) -> Vec<u32> {
+        let mut result = Vec::new();
+        if !self.nodes.is_empty() {
+            self.query_box_node(0, region, &mut result);
+        }
+        result
+    }
+
+    fn query_box_node(&self, node_index: usize, region: &BoundingBox, result: &mut Vec<u32>) {
+        let node = &self.nodes[node_index];
+        if !aabb_overlaps(&node.bounds, region) {
+            return;
+        }
+
+        match node.children {
+            Some((left, right)) => {
+                self.query_box_node(left, region, result);
+                self.query_box_node(right, region, result);
+            }
+            None => {
+                for k in node.start..node.start + node.count {
+                    let index = self.indices[k];
+                    let p = self.positions[index as usize];
+                    let inside = (0..3).all(|axis| p[axis] >= region.min[axis] && p[axis] <= region.max[axis]);
+                    if inside {
+                        result.push(index);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl GaussianCloud {
+    /// Build a BVH over this cloud's Gaussian centers, for accelerated picking and box queries.
+    pub fn build_bvh(&self) -> Bvh {
+        Bvh::build(self)
+    }
+}
@@ -8,11 +8,16 @@ pub struct Camera {
     pub aspect_ratio: f32,
     pub near: f32,
     pub far: f32,
+
+    /// Orbit (arcball) state: spherical offset from `target`, in degrees.
+    pub yaw: f32,
+    pub pitch: f32,
+    pub radius: f32,
 }
 
 impl Default for Camera {
     fn default() -> Self {
-        Self {
+        let mut camera = Self {
             position: [0.0, 0.0, 3.0],
             target: [0.0, 0.0, 0.0],
             up: [0.0, 1.0, 0.0],
@@ -20,26 +25,109 @@ impl Default for Camera {
             aspect_ratio: 16.0 / 9.0,
             near: 0.1,
             far: 100.0,
-        }
+            yaw: 0.0,
+            pitch: 0.0,
+            radius: 3.0,
+        };
+        camera.update_position();
+        camera
+    }
+}
+
+fn sub(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn add(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+fn scale(a: [f32; 3], s: f32) -> [f32; 3] {
+    [a[0] * s, a[1] * s, a[2] * s]
+}
+
+fn dot(a: [f32; 3], b: [f32; 3]) -> f32 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn cross(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn normalize(a: [f32; 3]) -> [f32; 3] {
+    let len = dot(a, a).sqrt();
+    if len > 1e-8 {
+        scale(a, 1.0 / len)
+    } else {
+        a
     }
 }
 
 impl Camera {
-    /// Get view matrix
+    /// Recompute `position` from the orbit (yaw/pitch/radius) state around `target`.
+    pub fn update_position(&mut self) {
+        let yaw_rad = self.yaw.to_radians();
+        let pitch_rad = self.pitch.to_radians();
+
+        let x = self.radius * pitch_rad.cos() * yaw_rad.sin();
+        let y = self.radius * pitch_rad.sin();
+        let z = self.radius * pitch_rad.cos() * yaw_rad.cos();
+
+        self.position = add(self.target, [x, y, z]);
+    }
+
+    /// Orbit the camera around `target` by the given yaw/pitch deltas (degrees).
+    pub fn rotate(&mut self, delta_yaw: f32, delta_pitch: f32) {
+        self.yaw += delta_yaw;
+        // Clamp away from the poles so `up` never flips direction.
+        self.pitch = (self.pitch + delta_pitch).clamp(-89.0, 89.0);
+        self.update_position();
+    }
+
+    /// Zoom the orbit radius in/out, clamped to a minimum distance.
+    pub fn zoom(&mut self, delta: f32) {
+        self.radius = (self.radius + delta).max(0.1);
+        self.update_position();
+    }
+
+    /// Get view matrix: a right-handed look-at matrix built from `position`/`target`/`up`.
+    ///
+    /// Stored column-major (`m[col][row]`) to match WGPU's expected layout.
     pub fn view_matrix(&self) -> [[f32; 4]; 4] {
-        // TODO: Implement look-at matrix
-        [[1.0, 0.0, 0.0, 0.0],
-            [0.0, 1.0, 0.0, 0.0],
-            [0.0, 0.0, 1.0, 0.0],
-            [0.0, 0.0, 0.0, 1.0]]
+        let forward = normalize(sub(self.target, self.position));
+        let right = normalize(cross(forward, self.up));
+        let true_up = cross(right, forward);
+
+        [
+            [right[0], true_up[0], -forward[0], 0.0],
+            [right[1], true_up[1], -forward[1], 0.0],
+            [right[2], true_up[2], -forward[2], 0.0],
+            [
+                -dot(right, self.position),
+                -dot(true_up, self.position),
+                dot(forward, self.position),
+                1.0,
+            ],
+        ]
     }
 
-    /// Get projection matrix
+    /// Get projection matrix: a right-handed perspective projection with WGPU's 0..1 depth range.
+    ///
+    /// Stored column-major (`m[col][row]`) to match WGPU's expected layout.
     pub fn projection_matrix(&self) -> [[f32; 4]; 4] {
-        // TODO: Implement perspective projection
-        [[1.0, 0.0, 0.0, 0.0],
-            [0.0, 1.0, 0.0, 0.0],
-            [0.0, 0.0, 1.0, 0.0],
-            [0.0, 0.0, 0.0, 1.0]]
+        let f = 1.0 / (self.fov.to_radians() / 2.0).tan();
+        let mut m = [[0.0; 4]; 4];
+
+        m[0][0] = f / self.aspect_ratio;
+        m[1][1] = f;
+        m[2][2] = self.far / (self.near - self.far);
+        m[2][3] = -1.0;
+        m[3][2] = (self.near * self.far) / (self.near - self.far);
+
+        m
     }
-}
\ No newline at end of file
+}
@@ -21,4 +21,7 @@ pub enum Error {
 
     #[error("Render error: {0}")]
     RenderError(String),
+
+    #[error("Serialization error: {0}")]
+    SerializationError(String),
 }
\ No newline at end of file
@@ -1,6 +1,171 @@
+use std::collections::HashMap;
+
 use crate::bounding_box::BoundingBox;
 use crate::error::{Error, Result};
 
+/// SH basis normalization constants, in the order the real-time 3DGS renderer uses them:
+/// band 0 (constant), band 1 (linear in the view direction), band 2 (quadratic), band 3 (cubic).
+pub const SH_C0: f32 = 0.282095;
+const SH_C1: f32 = 0.488603;
+const SH_C2: [f32; 5] = [1.092548, -1.092548, 0.315392, -1.092548, 0.546274];
+const SH_C3: [f32; 7] = [
+    -0.590043,
+    2.890611,
+    -0.457046,
+    0.373176,
+    -0.457046,
+    1.445306,
+    -0.590043,
+];
+
+/// Evaluate the real spherical-harmonics basis functions up to `degree` (0-3) for a (unit) view
+/// direction, returning `(degree + 1)^2` values ordered band-by-band. Matches the basis used by
+/// the reference 3D Gaussian Splatting renderer, so `sh_coefficients` round-trip through PLY
+/// files produced by other 3DGS tools.
+pub fn sh_basis(degree: usize, dir: [f32; 3]) -> Vec<f32> {
+    let [x, y, z] = dir;
+    let mut basis = vec![SH_C0];
+
+    if degree >= 1 {
+        basis.push(-SH_C1 * y);
+        basis.push(SH_C1 * z);
+        basis.push(-SH_C1 * x);
+    }
+
+    let (xx, yy, zz) = (x * x, y * y, z * z);
+    let (xy, yz, xz) = (x * y, y * z, x * z);
+
+    if degree >= 2 {
+        basis.push(SH_C2[0] * xy);
+        basis.push(SH_C2[1] * yz);
+        basis.push(SH_C2[2] * (2.0 * zz - xx - yy));
+        basis.push(SH_C2[3] * xz);
+        basis.push(SH_C2[4] * (xx - yy));
+    }
+
+    if degree >= 3 {
+        basis.push(SH_C3[0] * y * (3.0 * xx - yy));
+        basis.push(SH_C3[1] * xy * z);
+        basis.push(SH_C3[2] * y * (4.0 * zz - xx - yy));
+        basis.push(SH_C3[3] * z * (2.0 * zz - 3.0 * xx - 3.0 * yy));
+        basis.push(SH_C3[4] * x * (4.0 * zz - xx - yy));
+        basis.push(SH_C3[5] * z * (xx - yy));
+        basis.push(SH_C3[6] * x * (xx - 3.0 * yy));
+    }
+
+    basis
+}
+
+/// Number of SH coefficient triples (r, g, b per term) for a given band degree.
+pub fn sh_num_terms(degree: usize) -> usize {
+    (degree + 1) * (degree + 1)
+}
+
+fn sigmoid(x: f32) -> f32 {
+    1.0 / (1.0 + (-x).exp())
+}
+
+fn inverse_sigmoid(x: f32) -> f32 {
+    (x / (1.0 - x)).ln()
+}
+
+fn permute<T: Clone>(values: &[T], order: &[u32]) -> Vec<T> {
+    order.iter().map(|&i| values[i as usize].clone()).collect()
+}
+
+pub(crate) fn normalize_quat(q: [f32; 4]) -> [f32; 4] {
+    let norm = (q[0] * q[0] + q[1] * q[1] + q[2] * q[2] + q[3] * q[3]).sqrt();
+    if norm > 0.0 {
+        [q[0] / norm, q[1] / norm, q[2] / norm, q[3] / norm]
+    } else {
+        [1.0, 0.0, 0.0, 0.0]
+    }
+}
+
+/// Scalar types a PLY header `property` line can declare, restricted to the ones 3DGS tools
+/// actually emit for vertex data (no `list` properties).
+#[derive(Clone, Copy, Debug)]
+enum PlyScalarType {
+    Float,
+    Double,
+    Uchar,
+    Char,
+    Short,
+    Ushort,
+    Int,
+    Uint,
+}
+
+impl PlyScalarType {
+    fn parse(name: &str) -> Option<Self> {
+        Some(match name {
+            "float" | "float32" => Self::Float,
+            "double" | "float64" => Self::Double,
+            "uchar" | "uint8" => Self::Uchar,
+            "char" | "int8" => Self::Char,
+            "short" | "int16" => Self::Short,
+            "ushort" | "uint16" => Self::Ushort,
+            "int" | "int32" => Self::Int,
+            "uint" | "uint32" => Self::Uint,
+            _ => return None,
+        })
+    }
+
+    fn size(self) -> usize {
+        match self {
+            Self::Uchar | Self::Char => 1,
+            Self::Short | Self::Ushort => 2,
+            Self::Float | Self::Int | Self::Uint => 4,
+            Self::Double => 8,
+        }
+    }
+
+    /// Read this property out of `bytes` (the start of the field, not the whole vertex) as f32.
+    fn read(self, bytes: &[u8]) -> f32 {
+        match self {
+            Self::Float => f32::from_le_bytes(bytes[..4].try_into().unwrap()),
+            Self::Double => f64::from_le_bytes(bytes[..8].try_into().unwrap()) as f32,
+            Self::Uchar => bytes[0] as f32,
+            Self::Char => bytes[0] as i8 as f32,
+            Self::Short => i16::from_le_bytes(bytes[..2].try_into().unwrap()) as f32,
+            Self::Ushort => u16::from_le_bytes(bytes[..2].try_into().unwrap()) as f32,
+            Self::Int => i32::from_le_bytes(bytes[..4].try_into().unwrap()) as f32,
+            Self::Uint => u32::from_le_bytes(bytes[..4].try_into().unwrap()) as f32,
+        }
+    }
+}
+
+/// Byte layout of one named vertex property, as declared by a PLY header `property` line.
+struct PlyProperty {
+    ty: PlyScalarType,
+    offset: usize,
+}
+
+/// Parse the `property <type> <name>` lines of a PLY header (for the `vertex` element) into a
+/// name -> layout map plus the total per-vertex stride in bytes, so the reader can look fields up
+/// by name instead of assuming a fixed property order. Non-scalar lines (`format`, `element`,
+/// `comment`, `property list ...`) are skipped.
+fn parse_vertex_properties(header: &str) -> (HashMap<String, PlyProperty>, usize) {
+    let mut properties = HashMap::new();
+    let mut offset = 0;
+
+    for line in header.lines() {
+        let mut parts = line.split_whitespace();
+        if parts.next() != Some("property") {
+            continue;
+        }
+        let Some(ty) = parts.next().and_then(PlyScalarType::parse) else {
+            continue;
+        };
+        let Some(name) = parts.next() else { continue };
+
+        properties.insert(name.to_string(), PlyProperty { ty, offset });
+        offset += ty.size();
+    }
+
+    (properties, offset)
+}
+
 #[derive(Clone, Debug)]
 pub struct GaussianCloud {
     /// Number of Gaussians
@@ -66,9 +231,85 @@ impl GaussianCloud {
         self.rotations.push(rotation);
         self.colors.push(color);
         self.opacity.push(opacity);
+
+        if let Some(sh) = self.sh_coefficients.as_mut() {
+            sh.push(Vec::new());
+        }
+
+        self.count += 1;
+    }
+
+    /// Add a Gaussian with full spherical-harmonics coefficients for view-dependent color.
+    /// `sh_coeffs` holds `3 * (degree + 1)^2` floats: degree-0 (the DC term) first, as
+    /// `(r, g, b)` scaled by [`SH_C0`], followed by higher bands in the same `(r, g, b)` triples.
+    /// The flat `colors` entry is derived from the DC term so non-SH render paths still work.
+    pub fn add_gaussian_with_sh(
+        &mut self,
+        position: [f32; 3],
+        scale: [f32; 3],
+        rotation: [f32; 4],
+        sh_coeffs: Vec<f32>,
+        opacity: f32,
+    ) {
+        let dc_color = [
+            (0.5 + SH_C0 * sh_coeffs[0]).clamp(0.0, 1.0),
+            (0.5 + SH_C0 * sh_coeffs[1]).clamp(0.0, 1.0),
+            (0.5 + SH_C0 * sh_coeffs[2]).clamp(0.0, 1.0),
+        ];
+
+        self.positions.push(position);
+        self.scales.push(scale);
+        self.rotations.push(rotation);
+        self.colors.push(dc_color);
+        self.opacity.push(opacity);
+
+        let sh = self.sh_coefficients.get_or_insert_with(|| vec![Vec::new(); self.count]);
+        sh.push(sh_coeffs);
+
         self.count += 1;
     }
 
+    /// Evaluate view-dependent color for Gaussian `index` from `view_dir` (the unit direction
+    /// from the camera to the Gaussian, i.e. `normalize(position - camera_pos)`). Falls back to
+    /// the flat `colors` entry when this Gaussian has no (or degree-0-only) SH coefficients.
+    pub fn evaluate_color(&self, index: usize, view_dir: [f32; 3]) -> [f32; 3] {
+        let Some(coeffs) = self.sh_coefficients.as_ref().and_then(|sh| sh.get(index)) else {
+            return self.colors[index];
+        };
+
+        let num_terms = coeffs.len() / 3;
+        if num_terms == 0 {
+            return self.colors[index];
+        }
+
+        let degree = (num_terms as f32).sqrt() as usize - 1;
+        let basis = sh_basis(degree, view_dir);
+
+        let mut color = [0.5f32; 3];
+        for (term, &b) in basis.iter().enumerate() {
+            for c in 0..3 {
+                color[c] += coeffs[term * 3 + c] * b;
+            }
+        }
+
+        [color[0].clamp(0.0, 1.0), color[1].clamp(0.0, 1.0), color[2].clamp(0.0, 1.0)]
+    }
+
+    /// Permute every parallel per-Gaussian array (positions/scales/rotations/colors/opacity and
+    /// SH rows, when present) in place so `self.positions[i]` becomes the Gaussian that was at
+    /// `order[i]`. Used to bake a cloud into a fixed draw order (e.g. from a depth sort) instead
+    /// of re-deriving indices every frame. `order` must be a permutation of `0..self.count`.
+    pub fn reorder(&mut self, order: &[u32]) {
+        self.positions = permute(&self.positions, order);
+        self.scales = permute(&self.scales, order);
+        self.rotations = permute(&self.rotations, order);
+        self.colors = permute(&self.colors, order);
+        self.opacity = permute(&self.opacity, order);
+        if let Some(sh) = &self.sh_coefficients {
+            self.sh_coefficients = Some(permute(sh, order));
+        }
+    }
+
     /// Get bounding box of all Gaussians
     pub fn bounds(&self) -> BoundingBox {
         if self.count == 0 {
@@ -88,7 +329,14 @@ impl GaussianCloud {
         BoundingBox { min, max }
     }
 
-    /// Load GaussianCloud from .ply file
+    /// Load a GaussianCloud from a .ply file, driven entirely by its header's `property` lines
+    /// rather than an assumed byte layout, so it reads the files real 3DGS training pipelines
+    /// emit (variable `f_rest_*` counts, `double` fields, reordered properties, ...) as well as
+    /// the plain `red`/`green`/`blue` layout written by [`Self::to_ply`] without SH data.
+    ///
+    /// Per-vertex `opacity`, `scale_*` and `rot_*` are stored pre-activation in 3DGS PLY files,
+    /// so they're passed through `sigmoid`, `exp` and quaternion-normalize respectively on load.
+    /// SH degree is inferred from the `f_rest_*` count (9/24/45 -> degree 1/2/3).
     pub fn from_ply<P: AsRef<std::path::Path>>(path: P) -> Result<Self> {
         use std::io::Read;
         use std::fs::File;
@@ -111,6 +359,24 @@ impl GaussianCloud {
             .and_then(|s| s.parse::<usize>().ok())
             .ok_or_else(|| Error::InvalidGaussianCloud("No vertex count found".to_string()))?;
 
+        let (props, stride) = parse_vertex_properties(&header);
+        let prop = |name: &str| -> Result<&PlyProperty> {
+            props.get(name)
+                .ok_or_else(|| Error::InvalidGaussianCloud(format!("PLY file is missing property '{name}'")))
+        };
+        let read = |vertex: &[u8], p: &PlyProperty| p.ty.read(&vertex[p.offset..]);
+
+        let x = prop("x")?;
+        let y = prop("y")?;
+        let z = prop("z")?;
+        let opacity_prop = prop("opacity")?;
+        let scale_props = [prop("scale_0")?, prop("scale_1")?, prop("scale_2")?];
+        let rot_props = [prop("rot_0")?, prop("rot_1")?, prop("rot_2")?, prop("rot_3")?];
+
+        let num_rest = props.keys().filter(|name| name.starts_with("f_rest_")).count();
+        let has_sh = props.contains_key("f_dc_0");
+        let per_channel = num_rest / 3;
+
         // Binary data starts after "end_header\n"
         let data_start = header_end + 10 + 1;
         let data = &contents[data_start..];
@@ -118,57 +384,69 @@ impl GaussianCloud {
         let mut cloud = Self::with_capacity(vertex_count);
 
         for i in 0..vertex_count {
-            let offset = i * 59;
-            if offset + 59 > data.len() {
+            let offset = i * stride;
+            if offset + stride > data.len() {
                 break;
             }
+            let vertex = &data[offset..offset + stride];
 
-            let vertex_data = &data[offset..offset + 59];
-
-            // Position (bytes 0-11)
-            let position = [
-                f32::from_le_bytes([vertex_data[0], vertex_data[1], vertex_data[2], vertex_data[3]]),
-                f32::from_le_bytes([vertex_data[4], vertex_data[5], vertex_data[6], vertex_data[7]]),
-                f32::from_le_bytes([vertex_data[8], vertex_data[9], vertex_data[10], vertex_data[11]]),
-            ];
-
-            // Skip normals (bytes 12-23) - 3 floats
-
-            // Color (bytes 24-26) - 3 unsigned bytes
-            let color = [
-                vertex_data[24] as f32 / 255.0,
-                vertex_data[25] as f32 / 255.0,
-                vertex_data[26] as f32 / 255.0,
-            ];
-
-            // Opacity (bytes 27-30)
-            let opacity = f32::from_le_bytes([vertex_data[27], vertex_data[28], vertex_data[29], vertex_data[30]]);
-
-            // Scale (bytes 31-42)
+            let position = [read(vertex, x), read(vertex, y), read(vertex, z)];
+            let opacity = sigmoid(read(vertex, opacity_prop));
             let scale = [
-                f32::from_le_bytes([vertex_data[31], vertex_data[32], vertex_data[33], vertex_data[34]]),
-                f32::from_le_bytes([vertex_data[35], vertex_data[36], vertex_data[37], vertex_data[38]]),
-                f32::from_le_bytes([vertex_data[39], vertex_data[40], vertex_data[41], vertex_data[42]]),
+                read(vertex, scale_props[0]).exp(),
+                read(vertex, scale_props[1]).exp(),
+                read(vertex, scale_props[2]).exp(),
             ];
-
-            // Rotation (bytes 43-58)
-            let rotation = [
-                f32::from_le_bytes([vertex_data[43], vertex_data[44], vertex_data[45], vertex_data[46]]),
-                f32::from_le_bytes([vertex_data[47], vertex_data[48], vertex_data[49], vertex_data[50]]),
-                f32::from_le_bytes([vertex_data[51], vertex_data[52], vertex_data[53], vertex_data[54]]),
-                f32::from_le_bytes([vertex_data[55], vertex_data[56], vertex_data[57], vertex_data[58]]),
-            ];
-
-            cloud.add_gaussian(position, scale, rotation, color, opacity);
+            let rotation = normalize_quat([
+                read(vertex, rot_props[0]),
+                read(vertex, rot_props[1]),
+                read(vertex, rot_props[2]),
+                read(vertex, rot_props[3]),
+            ]);
+
+            if has_sh {
+                let mut sh_coeffs = vec![0.0f32; 3 + num_rest];
+                sh_coeffs[0] = read(vertex, prop("f_dc_0")?);
+                sh_coeffs[1] = read(vertex, prop("f_dc_1")?);
+                sh_coeffs[2] = read(vertex, prop("f_dc_2")?);
+
+                // f_rest_* is stored channel-major (all R terms, then all G, then all B) in the
+                // de-facto 3DGS layout; re-interleave into our (r, g, b) triples per band.
+                for band in 0..per_channel {
+                    for c in 0..3 {
+                        let name = format!("f_rest_{}", c * per_channel + band);
+                        sh_coeffs[3 + band * 3 + c] = read(vertex, prop(&name)?);
+                    }
+                }
+
+                cloud.add_gaussian_with_sh(position, scale, rotation, sh_coeffs, opacity);
+            } else {
+                let color = [
+                    read(vertex, prop("red")?) / 255.0,
+                    read(vertex, prop("green")?) / 255.0,
+                    read(vertex, prop("blue")?) / 255.0,
+                ];
+
+                cloud.add_gaussian(position, scale, rotation, color, opacity);
+            }
         }
 
         Ok(cloud)
     }
 
-    /// Export to PLY format (standard point cloud format)
+    /// Export to PLY format. When any Gaussian carries SH coefficients beyond the DC term,
+    /// writes the de-facto 3DGS layout (`f_dc_0..2` + `f_rest_*`, channel-major like reference
+    /// 3DGS tools) instead of the plain `red`/`green`/`blue` layout. `opacity` and `scale_*` are
+    /// written pre-activation (`logit(opacity)`, `ln(scale)`) to invert [`Self::from_ply`]'s
+    /// activations, matching the convention real 3DGS PLY files use.
     pub fn to_ply(&self) -> Result<Vec<u8>> {
         use std::io::Write;
 
+        let max_terms = self.sh_coefficients.as_ref()
+            .map(|sh| sh.iter().map(|c| c.len() / 3).max().unwrap_or(0))
+            .unwrap_or(0);
+        let num_rest = 3 * max_terms.saturating_sub(1);
+
         let mut buffer = Vec::new();
 
         // PLY header
@@ -181,9 +459,20 @@ impl GaussianCloud {
         writeln!(buffer, "property float nx")?;
         writeln!(buffer, "property float ny")?;
         writeln!(buffer, "property float nz")?;
-        writeln!(buffer, "property uchar red")?;
-        writeln!(buffer, "property uchar green")?;
-        writeln!(buffer, "property uchar blue")?;
+
+        if num_rest > 0 {
+            writeln!(buffer, "property float f_dc_0")?;
+            writeln!(buffer, "property float f_dc_1")?;
+            writeln!(buffer, "property float f_dc_2")?;
+            for i in 0..num_rest {
+                writeln!(buffer, "property float f_rest_{i}")?;
+            }
+        } else {
+            writeln!(buffer, "property uchar red")?;
+            writeln!(buffer, "property uchar green")?;
+            writeln!(buffer, "property uchar blue")?;
+        }
+
         writeln!(buffer, "property float opacity")?;
         writeln!(buffer, "property float scale_0")?;
         writeln!(buffer, "property float scale_1")?;
@@ -206,18 +495,48 @@ impl GaussianCloud {
             buffer.write_all(&0.0f32.to_le_bytes())?;
             buffer.write_all(&0.0f32.to_le_bytes())?;
 
-            // Color (convert to u8)
-            buffer.push((self.colors[i][0] * 255.0) as u8);
-            buffer.push((self.colors[i][1] * 255.0) as u8);
-            buffer.push((self.colors[i][2] * 255.0) as u8);
+            if num_rest > 0 {
+                let empty = Vec::new();
+                let coeffs = self.sh_coefficients.as_ref()
+                    .and_then(|sh| sh.get(i))
+                    .filter(|c| !c.is_empty())
+                    .unwrap_or(&empty);
+
+                let dc = if coeffs.len() >= 3 {
+                    [coeffs[0], coeffs[1], coeffs[2]]
+                } else {
+                    [
+                        (self.colors[i][0] - 0.5) / SH_C0,
+                        (self.colors[i][1] - 0.5) / SH_C0,
+                        (self.colors[i][2] - 0.5) / SH_C0,
+                    ]
+                };
+                buffer.write_all(&dc[0].to_le_bytes())?;
+                buffer.write_all(&dc[1].to_le_bytes())?;
+                buffer.write_all(&dc[2].to_le_bytes())?;
+
+                let terms = coeffs.len() / 3;
+                let per_channel = num_rest / 3;
+                for c in 0..3 {
+                    for band in 0..per_channel {
+                        let v = if band + 1 < terms { coeffs[(band + 1) * 3 + c] } else { 0.0 };
+                        buffer.write_all(&v.to_le_bytes())?;
+                    }
+                }
+            } else {
+                // Color (convert to u8)
+                buffer.push((self.colors[i][0] * 255.0) as u8);
+                buffer.push((self.colors[i][1] * 255.0) as u8);
+                buffer.push((self.colors[i][2] * 255.0) as u8);
+            }
 
-            // Opacity
-            buffer.write_all(&self.opacity[i].to_le_bytes())?;
+            // Opacity (pre-activation, inverts from_ply's sigmoid)
+            buffer.write_all(&inverse_sigmoid(self.opacity[i]).to_le_bytes())?;
 
-            // Scale
-            buffer.write_all(&self.scales[i][0].to_le_bytes())?;
-            buffer.write_all(&self.scales[i][1].to_le_bytes())?;
-            buffer.write_all(&self.scales[i][2].to_le_bytes())?;
+            // Scale (pre-activation, inverts from_ply's exp)
+            buffer.write_all(&self.scales[i][0].ln().to_le_bytes())?;
+            buffer.write_all(&self.scales[i][1].ln().to_le_bytes())?;
+            buffer.write_all(&self.scales[i][2].ln().to_le_bytes())?;
 
             // Rotation
             buffer.write_all(&self.rotations[i][0].to_le_bytes())?;
@@ -240,6 +559,15 @@ impl GaussianCloud {
                 "Inconsistent array lengths".to_string()
             ));
         }
+
+        if let Some(sh) = &self.sh_coefficients {
+            if sh.len() != self.count {
+                return Err(Error::InvalidGaussianCloud(
+                    "Inconsistent sh_coefficients length".to_string()
+                ));
+            }
+        }
+
         Ok(())
     }
 }
\ No newline at end of file
@@ -0,0 +1,437 @@
+use std::collections::HashMap;
+
+use crate::gaussian_cloud::GaussianCloud;
+use crate::error::{Error, Result};
+
+/// A triangle mesh extracted from a [`GaussianCloud`]'s implicit density field.
+#[derive(Clone, Debug, Default)]
+pub struct Mesh {
+    pub positions: Vec<[f32; 3]>,
+    pub normals: Vec<[f32; 3]>,
+    pub indices: Vec<u32>,
+}
+
+/// Parameters controlling the marching-cubes extraction.
+#[derive(Clone, Debug)]
+pub struct MeshExtractionConfig {
+    /// Number of voxels along each axis of the cloud's (padded) bounding box.
+    pub resolution: usize,
+    /// Density threshold a voxel must cross to be considered "inside" the surface.
+    pub iso_threshold: f32,
+}
+
+impl Default for MeshExtractionConfig {
+    fn default() -> Self {
+        Self {
+            resolution: 64,
+            iso_threshold: 1.0,
+        }
+    }
+}
+
+/// Invert a 3x3 covariance matrix built from a Gaussian's scale and rotation.
+///
+/// `Sigma = R * diag(scale^2) * R^T`, so `Sigma^-1 = R * diag(1/scale^2) * R^T`.
+fn inverse_covariance(scale: [f32; 3], rotation: [f32; 4]) -> [[f32; 3]; 3] {
+    let [w, x, y, z] = rotation;
+    let norm = (w * w + x * x + y * y + z * z).sqrt().max(1e-8);
+    let (w, x, y, z) = (w / norm, x / norm, y / norm, z / norm);
+
+    // Rotation matrix from the unit quaternion.
+    let r = [
+        [
+            1.0 - 2.0 * (y * y + z * z),
+            2.0 * (x * y - w * z),
+            2.0 * (x * z + w * y),
+        ],
+        [
+            2.0 * (x * y + w * z),
+            1.0 - 2.0 * (x * x + z * z),
+            2.0 * (y * z - w * x),
+        ],
+        [
+            2.0 * (x * z - w * y),
+            2.0 * (y * z + w * x),
+            1.0 - 2.0 * (x * x + y * y),
+        ],
+    ];
+
+    let inv_sq = [
+        1.0 / (scale[0] * scale[0]).max(1e-8),
+        1.0 / (scale[1] * scale[1]).max(1e-8),
+        1.0 / (scale[2] * scale[2]).max(1e-8),
+    ];
+
+    // Sigma^-1 = R * diag(inv_sq) * R^T
+    let mut out = [[0.0f32; 3]; 3];
+    for i in 0..3 {
+        for j in 0..3 {
+            out[i][j] = r[i][0] * inv_sq[0] * r[j][0]
+                + r[i][1] * inv_sq[1] * r[j][1]
+                + r[i][2] * inv_sq[2] * r[j][2];
+        }
+    }
+    out
+}
+
+fn mahalanobis_sq(d: [f32; 3], sigma_inv: [[f32; 3]; 3]) -> f32 {
+    let md = [
+        sigma_inv[0][0] * d[0] + sigma_inv[0][1] * d[1] + sigma_inv[0][2] * d[2],
+        sigma_inv[1][0] * d[0] + sigma_inv[1][1] * d[1] + sigma_inv[1][2] * d[2],
+        sigma_inv[2][0] * d[0] + sigma_inv[2][1] * d[1] + sigma_inv[2][2] * d[2],
+    ];
+    d[0] * md[0] + d[1] * md[1] + d[2] * md[2]
+}
+
+/// Voxelize a [`GaussianCloud`] into a scalar density grid over its bounding box.
+fn voxelize(cloud: &GaussianCloud, resolution: usize) -> (Vec<f32>, [f32; 3], [f32; 3]) {
+    let bounds = cloud.bounds();
+    let size = bounds.size();
+    let pad = [size[0].max(1e-3) * 0.1, size[1].max(1e-3) * 0.1, size[2].max(1e-3) * 0.1];
+    let min = [bounds.min[0] - pad[0], bounds.min[1] - pad[1], bounds.min[2] - pad[2]];
+    let max = [bounds.max[0] + pad[0], bounds.max[1] + pad[1], bounds.max[2] + pad[2]];
+    let extent = [
+        (max[0] - min[0]).max(1e-3),
+        (max[1] - min[1]).max(1e-3),
+        (max[2] - min[2]).max(1e-3),
+    ];
+
+    let dim = resolution + 1;
+    let mut grid = vec![0.0f32; dim * dim * dim];
+    let cell = [
+        extent[0] / resolution as f32,
+        extent[1] / resolution as f32,
+        extent[2] / resolution as f32,
+    ];
+
+    // Support radius cutoff (in voxels) so each Gaussian only touches nearby cells.
+    for i in 0..cloud.count {
+        let pos = cloud.positions[i];
+        let opacity = cloud.opacity[i];
+        let sigma_inv = inverse_covariance(cloud.scales[i], cloud.rotations[i]);
+        let max_scale = cloud.scales[i][0].max(cloud.scales[i][1]).max(cloud.scales[i][2]);
+        let radius = (max_scale * 3.0).max(cell[0].max(cell[1]).max(cell[2]));
+
+        let lo = [
+            (((pos[0] - radius - min[0]) / cell[0]).floor() as isize).max(0),
+            (((pos[1] - radius - min[1]) / cell[1]).floor() as isize).max(0),
+            (((pos[2] - radius - min[2]) / cell[2]).floor() as isize).max(0),
+        ];
+        let hi = [
+            (((pos[0] + radius - min[0]) / cell[0]).ceil() as isize).min(dim as isize - 1),
+            (((pos[1] + radius - min[1]) / cell[1]).ceil() as isize).min(dim as isize - 1),
+            (((pos[2] + radius - min[2]) / cell[2]).ceil() as isize).min(dim as isize - 1),
+        ];
+
+        for gz in lo[2]..=hi[2] {
+            for gy in lo[1]..=hi[1] {
+                for gx in lo[0]..=hi[0] {
+                    let voxel_center = [
+                        min[0] + gx as f32 * cell[0],
+                        min[1] + gy as f32 * cell[1],
+                        min[2] + gz as f32 * cell[2],
+                    ];
+                    let d = [
+                        voxel_center[0] - pos[0],
+                        voxel_center[1] - pos[1],
+                        voxel_center[2] - pos[2],
+                    ];
+                    let m2 = mahalanobis_sq(d, sigma_inv);
+                    let density = opacity * (-0.5 * m2).exp();
+
+                    let idx = (gz as usize * dim + gy as usize) * dim + gx as usize;
+                    grid[idx] += density;
+                }
+            }
+        }
+    }
+
+    (grid, min, cell)
+}
+
+/// Extract a triangle mesh from a [`GaussianCloud`] via marching cubes over its density field.
+pub fn extract_mesh(cloud: &GaussianCloud, config: &MeshExtractionConfig) -> Mesh {
+    let resolution = config.resolution.max(1);
+    let dim = resolution + 1;
+    let (grid, min, cell) = voxelize(cloud, resolution);
+    let iso = config.iso_threshold;
+
+    let sample = |x: usize, y: usize, z: usize| grid[(z * dim + y) * dim + x];
+    let corner_pos = |x: usize, y: usize, z: usize| {
+        [
+            min[0] + x as f32 * cell[0],
+            min[1] + y as f32 * cell[1],
+            min[2] + z as f32 * cell[2],
+        ]
+    };
+
+    let mut positions: Vec<[f32; 3]> = Vec::new();
+    let mut indices: Vec<u32> = Vec::new();
+    let mut edge_cache: HashMap<(u64, u64), u32> = HashMap::new();
+
+    // Corner offsets and edge-to-corner connectivity for a standard marching cubes cell.
+    const CORNER_OFFSETS: [[usize; 3]; 8] = [
+        [0, 0, 0], [1, 0, 0], [1, 1, 0], [0, 1, 0],
+        [0, 0, 1], [1, 0, 1], [1, 1, 1], [0, 1, 1],
+    ];
+    const EDGE_CORNERS: [(usize, usize); 12] = [
+        (0, 1), (1, 2), (2, 3), (3, 0),
+        (4, 5), (5, 6), (6, 7), (7, 4),
+        (0, 4), (1, 5), (2, 6), (3, 7),
+    ];
+
+    let mut cell_vertex_indices = [0u32; 12];
+
+    for z in 0..resolution {
+        for y in 0..resolution {
+            for x in 0..resolution {
+                let corner_values: [f32; 8] = std::array::from_fn(|c| {
+                    let [ox, oy, oz] = CORNER_OFFSETS[c];
+                    sample(x + ox, y + oy, z + oz)
+                });
+
+                let mut mask = 0u8;
+                for (c, &v) in corner_values.iter().enumerate() {
+                    if v > iso {
+                        mask |= 1 << c;
+                    }
+                }
+                if mask == 0 || mask == 0xFF {
+                    continue;
+                }
+
+                let active_edges = MC_EDGE_TABLE[mask as usize];
+                if active_edges == 0 {
+                    continue;
+                }
+
+                for (edge_idx, &(c0, c1)) in EDGE_CORNERS.iter().enumerate() {
+                    if active_edges & (1 << edge_idx) == 0 {
+                        continue;
+                    }
+
+                    let [ox0, oy0, oz0] = CORNER_OFFSETS[c0];
+                    let [ox1, oy1, oz1] = CORNER_OFFSETS[c1];
+                    let gx0 = (x + ox0) as u64;
+                    let gy0 = (y + oy0) as u64;
+                    let gz0 = (z + oz0) as u64;
+                    let gx1 = (x + ox1) as u64;
+                    let gy1 = (y + oy1) as u64;
+                    let gz1 = (z + oz1) as u64;
+
+                    let key_a = (gz0 * dim as u64 + gy0) * dim as u64 + gx0;
+                    let key_b = (gz1 * dim as u64 + gy1) * dim as u64 + gx1;
+                    let key = if key_a < key_b { (key_a, key_b) } else { (key_b, key_a) };
+
+                    let vertex_index = *edge_cache.entry(key).or_insert_with(|| {
+                        let v0 = corner_values[c0];
+                        let v1 = corner_values[c1];
+                        let t = if (v1 - v0).abs() > 1e-8 {
+                            (iso - v0) / (v1 - v0)
+                        } else {
+                            0.5
+                        };
+                        let t = t.clamp(0.0, 1.0);
+
+                        let p0 = corner_pos(x + ox0, y + oy0, z + oz0);
+                        let p1 = corner_pos(x + ox1, y + oy1, z + oz1);
+                        let p = [
+                            p0[0] + (p1[0] - p0[0]) * t,
+                            p0[1] + (p1[1] - p0[1]) * t,
+                            p0[2] + (p1[2] - p0[2]) * t,
+                        ];
+
+                        positions.push(p);
+                        (positions.len() - 1) as u32
+                    });
+
+                    cell_vertex_indices[edge_idx] = vertex_index;
+                }
+
+                // Triangle table entries are wound for a right-handed, outward-facing surface.
+                let tri_edges = &MC_TRI_TABLE[mask as usize];
+                let mut i = 0;
+                while tri_edges[i] != -1 {
+                    indices.push(cell_vertex_indices[tri_edges[i] as usize]);
+                    indices.push(cell_vertex_indices[tri_edges[i + 1] as usize]);
+                    indices.push(cell_vertex_indices[tri_edges[i + 2] as usize]);
+                    i += 3;
+                }
+            }
+        }
+    }
+
+    let mut mesh = Mesh {
+        positions,
+        normals: Vec::new(),
+        indices,
+    };
+    mesh.recompute_normals();
+    mesh
+}
+
+impl Mesh {
+    /// Recompute smooth per-vertex normals by averaging adjacent face normals.
+    pub fn recompute_normals(&mut self) {
+        let mut normals = vec![[0.0f32; 3]; self.positions.len()];
+
+        for tri in self.indices.chunks_exact(3) {
+            let (a, b, c) = (tri[0] as usize, tri[1] as usize, tri[2] as usize);
+            let pa = self.positions[a];
+            let pb = self.positions[b];
+            let pc = self.positions[c];
+
+            let e1 = [pb[0] - pa[0], pb[1] - pa[1], pb[2] - pa[2]];
+            let e2 = [pc[0] - pa[0], pc[1] - pa[1], pc[2] - pa[2]];
+            let face_normal = [
+                e1[1] * e2[2] - e1[2] * e2[1],
+                e1[2] * e2[0] - e1[0] * e2[2],
+                e1[0] * e2[1] - e1[1] * e2[0],
+            ];
+
+            for &v in &[a, b, c] {
+                normals[v][0] += face_normal[0];
+                normals[v][1] += face_normal[1];
+                normals[v][2] += face_normal[2];
+            }
+        }
+
+        for n in normals.iter_mut() {
+            let len = (n[0] * n[0] + n[1] * n[1] + n[2] * n[2]).sqrt();
+            if len > 1e-8 {
+                n[0] /= len;
+                n[1] /= len;
+                n[2] /= len;
+            }
+        }
+
+        self.normals = normals;
+    }
+
+    /// Export this mesh to a Wavefront OBJ text buffer.
+    pub fn to_obj(&self) -> Result<Vec<u8>> {
+        use std::io::Write;
+        let mut buffer = Vec::new();
+
+        for p in &self.positions {
+            writeln!(buffer, "v {} {} {}", p[0], p[1], p[2])?;
+        }
+        for n in &self.normals {
+            writeln!(buffer, "vn {} {} {}", n[0], n[1], n[2])?;
+        }
+        for tri in self.indices.chunks_exact(3) {
+            writeln!(
+                buffer,
+                "f {}//{} {}//{} {}//{}",
+                tri[0] + 1, tri[0] + 1,
+                tri[1] + 1, tri[1] + 1,
+                tri[2] + 1, tri[2] + 1,
+            )?;
+        }
+
+        Ok(buffer)
+    }
+
+    /// Export this mesh to a minimal binary glTF (.glb) container with one mesh primitive.
+    pub fn to_gltf(&self) -> Result<Vec<u8>> {
+        let mut bin = Vec::new();
+        for p in &self.positions {
+            bin.extend_from_slice(&p[0].to_le_bytes());
+            bin.extend_from_slice(&p[1].to_le_bytes());
+            bin.extend_from_slice(&p[2].to_le_bytes());
+        }
+        let normals_offset = bin.len();
+        for n in &self.normals {
+            bin.extend_from_slice(&n[0].to_le_bytes());
+            bin.extend_from_slice(&n[1].to_le_bytes());
+            bin.extend_from_slice(&n[2].to_le_bytes());
+        }
+        let indices_offset = bin.len();
+        for &idx in &self.indices {
+            bin.extend_from_slice(&idx.to_le_bytes());
+        }
+        while bin.len() % 4 != 0 {
+            bin.push(0);
+        }
+
+        let (min, max) = self.position_bounds();
+
+        let json = format!(
+            r#"{{"asset":{{"version":"2.0","generator":"gj-core mesh export"}},"scene":0,"scenes":[{{"nodes":[0]}}],"nodes":[{{"mesh":0}}],"meshes":[{{"primitives":[{{"attributes":{{"POSITION":0,"NORMAL":1}},"indices":2}}]}}],"buffers":[{{"byteLength":{bin_len}}}],"bufferViews":[{{"buffer":0,"byteOffset":0,"byteLength":{normals_offset},"target":34962}},{{"buffer":0,"byteOffset":{normals_offset},"byteLength":{normals_len},"target":34962}},{{"buffer":0,"byteOffset":{indices_offset},"byteLength":{indices_len},"target":34963}}],"accessors":[{{"bufferView":0,"componentType":5126,"count":{vertex_count},"type":"VEC3","min":[{min0},{min1},{min2}],"max":[{max0},{max1},{max2}]}},{{"bufferView":1,"componentType":5126,"count":{vertex_count},"type":"VEC3"}},{{"bufferView":2,"componentType":5125,"count":{index_count},"type":"SCALAR"}}]}}"#,
+            bin_len = bin.len(),
+            normals_offset = normals_offset,
+            normals_len = indices_offset - normals_offset,
+            indices_offset = indices_offset,
+            indices_len = bin.len() - indices_offset,
+            vertex_count = self.positions.len(),
+            index_count = self.indices.len(),
+            min0 = min[0], min1 = min[1], min2 = min[2],
+            max0 = max[0], max1 = max[1], max2 = max[2],
+        );
+
+        let mut json_bytes = json.into_bytes();
+        while json_bytes.len() % 4 != 0 {
+            json_bytes.push(b' ');
+        }
+
+        let mut glb = Vec::new();
+        glb.extend_from_slice(b"glTF");
+        glb.extend_from_slice(&2u32.to_le_bytes());
+        let total_len = 12 + 8 + json_bytes.len() + 8 + bin.len();
+        glb.extend_from_slice(&(total_len as u32).to_le_bytes());
+
+        glb.extend_from_slice(&(json_bytes.len() as u32).to_le_bytes());
+        glb.extend_from_slice(b"JSON");
+        glb.extend_from_slice(&json_bytes);
+
+        glb.extend_from_slice(&(bin.len() as u32).to_le_bytes());
+        glb.extend_from_slice(b"BIN\0");
+        glb.extend_from_slice(&bin);
+
+        Ok(glb)
+    }
+
+    fn position_bounds(&self) -> ([f32; 3], [f32; 3]) {
+        let mut min = [f32::INFINITY; 3];
+        let mut max = [f32::NEG_INFINITY; 3];
+        for p in &self.positions {
+            for i in 0..3 {
+                min[i] = min[i].min(p[i]);
+                max[i] = max[i].max(p[i]);
+            }
+        }
+        if self.positions.is_empty() {
+            min = [0.0; 3];
+            max = [0.0; 3];
+        }
+        (min, max)
+    }
+}
+
+/// Save a mesh to disk, dispatching on the file extension (`.obj` or `.gltf`/`.glb`).
+pub fn save_mesh<P: AsRef<std::path::Path>>(mesh: &Mesh, path: P) -> Result<()> {
+    let path = path.as_ref();
+    let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+
+    let data = match ext.to_ascii_lowercase().as_str() {
+        "obj" => mesh.to_obj()?,
+        "gltf" | "glb" => mesh.to_gltf()?,
+        other => {
+            return Err(Error::InvalidConfig(format!(
+                "Unsupported mesh export extension: .{other}"
+            )))
+        }
+    };
+
+    std::fs::write(path, data)?;
+    Ok(())
+}
+
+// Standard marching cubes edge table: bit `i` is set if cube edge `i` is crossed by the surface.
+static MC_EDGE_TABLE: [u16; 256] = mc_tables::EDGE_TABLE;
+// Standard marching cubes triangle table: up to 5 triangles (15 edge indices, -1 terminated) per case.
+static MC_TRI_TABLE: [[i8; 16]; 256] = mc_tables::TRI_TABLE;
+
+mod mc_tables {
+    include!("mesh_tables.rs");
+}
@@ -0,0 +1,108 @@
+//! Blend two [`GaussianCloud`]s into an intermediate state for particle-morph animation: lerp
+//! positions/scales/colors/opacity/SH coefficients, slerp rotation quaternions.
+
+use crate::gaussian_cloud::{normalize_quat, GaussianCloud};
+
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+fn lerp3(a: [f32; 3], b: [f32; 3], t: f32) -> [f32; 3] {
+    [lerp(a[0], b[0], t), lerp(a[1], b[1], t), lerp(a[2], b[2], t)]
+}
+
+/// Spherical interpolation between two rotation quaternions: flips `b`'s sign to take the
+/// shorter arc when the quaternions point into opposite hemispheres, and falls back to a
+/// normalized lerp when they're nearly collinear, where slerp's `sin(theta_0)` divisor blows up.
+fn slerp_quat(a: [f32; 4], b: [f32; 4], t: f32) -> [f32; 4] {
+    let mut dot = a[0] * b[0] + a[1] * b[1] + a[2] * b[2] + a[3] * b[3];
+    let mut b = b;
+    if dot < 0.0 {
+        b = [-b[0], -b[1], -b[2], -b[3]];
+        dot = -dot;
+    }
+
+    if dot > 0.9995 {
+        return normalize_quat([
+            lerp(a[0], b[0], t),
+            lerp(a[1], b[1], t),
+            lerp(a[2], b[2], t),
+            lerp(a[3], b[3], t),
+        ]);
+    }
+
+    let theta_0 = dot.acos();
+    let theta = theta_0 * t;
+    let sin_theta_0 = theta_0.sin();
+    let sin_theta = theta.sin();
+
+    let s0 = (theta_0 - theta).sin() / sin_theta_0;
+    let s1 = sin_theta / sin_theta_0;
+
+    normalize_quat([
+        a[0] * s0 + b[0] * s1,
+        a[1] * s0 + b[1] * s1,
+        a[2] * s0 + b[2] * s1,
+        a[3] * s0 + b[3] * s1,
+    ])
+}
+
+/// Pad `cloud` up to `target_count` Gaussians with zero-opacity copies of `reference`'s trailing
+/// entries (positioned where `reference`'s extra Gaussians are, but invisible), so a morph grows
+/// from nothing rather than popping in. A no-op if `cloud` is already long enough.
+fn pad_to(cloud: &GaussianCloud, target_count: usize, reference: &GaussianCloud) -> GaussianCloud {
+    if cloud.count >= target_count {
+        return cloud.clone();
+    }
+
+    let mut padded = cloud.clone();
+    for i in cloud.count..target_count {
+        padded.add_gaussian(
+            reference.positions[i],
+            reference.scales[i],
+            reference.rotations[i],
+            reference.colors[i],
+            0.0,
+        );
+    }
+    padded
+}
+
+impl GaussianCloud {
+    /// Blend `self` and `other` at `t` (0 = `self`, 1 = `other`): lerp positions, scales,
+    /// colors, opacity, and SH coefficients; slerp rotations. If the clouds have different
+    /// counts, the smaller one is padded with zero-opacity Gaussians at the larger one's
+    /// positions so every index lines up.
+    pub fn morph(&self, other: &GaussianCloud, t: f32) -> GaussianCloud {
+        let target_count = self.count.max(other.count);
+        let a = pad_to(self, target_count, other);
+        let b = pad_to(other, target_count, self);
+
+        let mut result = GaussianCloud::with_capacity(target_count);
+        for i in 0..target_count {
+            result.add_gaussian(
+                lerp3(a.positions[i], b.positions[i], t),
+                lerp3(a.scales[i], b.scales[i], t),
+                slerp_quat(a.rotations[i], b.rotations[i], t),
+                lerp3(a.colors[i], b.colors[i], t),
+                lerp(a.opacity[i], b.opacity[i], t),
+            );
+        }
+
+        if let (Some(sh_a), Some(sh_b)) = (&a.sh_coefficients, &b.sh_coefficients) {
+            let sh = (0..target_count)
+                .map(|i| {
+                    let (row_a, row_b) = (&sh_a[i], &sh_b[i]);
+                    if row_a.len() == row_b.len() {
+                        row_a.iter().zip(row_b).map(|(&x, &y)| lerp(x, y, t)).collect()
+                    } else {
+                        Vec::new()
+                    }
+                })
+                .collect();
+            result.sh_coefficients = Some(sh);
+        }
+
+        result
+    }
+}
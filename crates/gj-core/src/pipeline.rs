@@ -2,12 +2,32 @@ use std::time::Duration;
 
 use crate::gaussian_cloud::GaussianCloud;
 use crate::error::Result;
+use crate::progress::ProgressCallback;
 
 /// Trait for 3D generation pipelines
 pub trait Pipeline3D: Send + Sync {
     /// Generate 3D Gaussians from text prompt
     fn generate(&self, prompt: &str, config: &PipelineConfig) -> Result<GaussianCloud>;
 
+    /// Like [`Self::generate`], but reports progress (and, when the pipeline can produce one, a
+    /// coarse intermediate cloud for live preview) through `callback` as the run progresses,
+    /// instead of blocking silently until the final result. `config`'s `inference_steps` is the
+    /// natural step count to report against for pipelines that run an iterative diffusion/LGM
+    /// loop. Pipelines that can't report anything finer than start/end fall back to wrapping the
+    /// synchronous [`Self::generate`] with a single 0%/100% update.
+    fn generate_with_progress(
+        &self,
+        prompt: &str,
+        config: &PipelineConfig,
+        callback: &mut dyn ProgressCallback,
+    ) -> Result<GaussianCloud> {
+        callback.update(0.0, "Starting generation...");
+        let cloud = self.generate(prompt, config)?;
+        callback.preview(&cloud);
+        callback.update(1.0, "Generation complete");
+        Ok(cloud)
+    }
+
     /// Get pipeline name
     fn name(&self) -> &str;
 
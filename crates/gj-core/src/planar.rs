@@ -0,0 +1,131 @@
+//! Compact, planar, half-precision storage for [`GaussianCloud`]: each attribute lives in its
+//! own contiguous `Vec<f16>` (rather than a `Vec<[f32; N]>` of interleaved structs) and is
+//! quantized to half precision, roughly halving memory versus the full `f32` layout. Meant for
+//! holding million-Gaussian scenes resident and for streaming, where [`GaussianCloud`]'s
+//! structured layout is both heavier and less cache-friendly to scan attribute-at-a-time.
+
+use half::f16;
+
+use crate::gaussian_cloud::GaussianCloud;
+
+/// Planar, `f16`-quantized form of a [`GaussianCloud`]. Widen individual attributes back to
+/// `f32` with the `*_f32` accessors, or reconstitute a full [`GaussianCloud`] with
+/// [`Self::to_f32`].
+#[derive(Clone, Debug)]
+pub struct GaussianCloudF16Planar {
+    pub count: usize,
+
+    pub pos_x: Vec<f16>,
+    pub pos_y: Vec<f16>,
+    pub pos_z: Vec<f16>,
+
+    pub scale_x: Vec<f16>,
+    pub scale_y: Vec<f16>,
+    pub scale_z: Vec<f16>,
+
+    /// Quaternion components (w, x, y, z). Stays normalized after round-tripping through `f16`
+    /// precision: [`Self::to_f32`] renormalizes each quaternion when widening back to `f32`.
+    pub rot_w: Vec<f16>,
+    pub rot_x: Vec<f16>,
+    pub rot_y: Vec<f16>,
+    pub rot_z: Vec<f16>,
+
+    pub color_r: Vec<f16>,
+    pub color_g: Vec<f16>,
+    pub color_b: Vec<f16>,
+
+    pub opacity: Vec<f16>,
+
+    /// SH rows, quantized the same way as `GaussianCloud::sh_coefficients`; still one `Vec<f16>`
+    /// per Gaussian since the per-Gaussian term count can vary.
+    pub sh_coefficients: Option<Vec<Vec<f16>>>,
+}
+
+impl GaussianCloudF16Planar {
+    /// Widen Gaussian `index`'s position back to `f32`.
+    pub fn position(&self, index: usize) -> [f32; 3] {
+        [self.pos_x[index].to_f32(), self.pos_y[index].to_f32(), self.pos_z[index].to_f32()]
+    }
+
+    /// Widen Gaussian `index`'s scale back to `f32`.
+    pub fn scale(&self, index: usize) -> [f32; 3] {
+        [self.scale_x[index].to_f32(), self.scale_y[index].to_f32(), self.scale_z[index].to_f32()]
+    }
+
+    /// Widen Gaussian `index`'s rotation quaternion back to `f32`.
+    pub fn rotation(&self, index: usize) -> [f32; 4] {
+        [
+            self.rot_w[index].to_f32(),
+            self.rot_x[index].to_f32(),
+            self.rot_y[index].to_f32(),
+            self.rot_z[index].to_f32(),
+        ]
+    }
+
+    /// Widen Gaussian `index`'s flat color back to `f32`.
+    pub fn color(&self, index: usize) -> [f32; 3] {
+        [self.color_r[index].to_f32(), self.color_g[index].to_f32(), self.color_b[index].to_f32()]
+    }
+
+    /// Widen the full packed cloud back into a structured, `f32` [`GaussianCloud`].
+    pub fn to_f32(&self) -> GaussianCloud {
+        let mut cloud = GaussianCloud::with_capacity(self.count);
+
+        for i in 0..self.count {
+            let rotation = crate::gaussian_cloud::normalize_quat(self.rotation(i));
+            let opacity = self.opacity[i].to_f32();
+
+            match self.sh_coefficients.as_ref().and_then(|sh| sh.get(i)) {
+                Some(sh) if !sh.is_empty() => {
+                    let sh_coeffs: Vec<f32> = sh.iter().map(|c| c.to_f32()).collect();
+                    cloud.add_gaussian_with_sh(self.position(i), self.scale(i), rotation, sh_coeffs, opacity);
+                }
+                _ => {
+                    cloud.add_gaussian(self.position(i), self.scale(i), rotation, self.color(i), opacity);
+                }
+            }
+        }
+
+        cloud
+    }
+}
+
+impl GaussianCloud {
+    /// Pack this cloud into the planar, half-precision [`GaussianCloudF16Planar`] layout.
+    pub fn to_f16_planar(&self) -> GaussianCloudF16Planar {
+        let widen = f16::from_f32;
+
+        GaussianCloudF16Planar {
+            count: self.count,
+
+            pos_x: self.positions.iter().map(|p| widen(p[0])).collect(),
+            pos_y: self.positions.iter().map(|p| widen(p[1])).collect(),
+            pos_z: self.positions.iter().map(|p| widen(p[2])).collect(),
+
+            scale_x: self.scales.iter().map(|s| widen(s[0])).collect(),
+            scale_y: self.scales.iter().map(|s| widen(s[1])).collect(),
+            scale_z: self.scales.iter().map(|s| widen(s[2])).collect(),
+
+            rot_w: self.rotations.iter().map(|r| widen(r[0])).collect(),
+            rot_x: self.rotations.iter().map(|r| widen(r[1])).collect(),
+            rot_y: self.rotations.iter().map(|r| widen(r[2])).collect(),
+            rot_z: self.rotations.iter().map(|r| widen(r[3])).collect(),
+
+            color_r: self.colors.iter().map(|c| widen(c[0])).collect(),
+            color_g: self.colors.iter().map(|c| widen(c[1])).collect(),
+            color_b: self.colors.iter().map(|c| widen(c[2])).collect(),
+
+            opacity: self.opacity.iter().map(|&o| widen(o)).collect(),
+
+            sh_coefficients: self.sh_coefficients.as_ref().map(|sh| {
+                sh.iter().map(|row| row.iter().map(|&c| widen(c)).collect()).collect()
+            }),
+        }
+    }
+
+    /// Unpack a planar, half-precision cloud back into this layout. Shorthand for
+    /// `planar.to_f32()`.
+    pub fn from_f16_planar(planar: &GaussianCloudF16Planar) -> Self {
+        planar.to_f32()
+    }
+}
@@ -7,6 +7,11 @@ pub trait ProgressCallback: Send {
     fn should_cancel(&self) -> bool {
         false
     }
+
+    /// Receive a coarse intermediate `GaussianCloud` for live preview during a long-running
+    /// generation. Default no-op; callbacks that only care about the progress text/fraction can
+    /// ignore it.
+    fn preview(&mut self, _cloud: &crate::gaussian_cloud::GaussianCloud) {}
 }
 
 /// Simple progress tracker
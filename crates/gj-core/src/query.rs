@@ -0,0 +1,77 @@
+//! Non-destructive spatial selection over a [`GaussianCloud`]: crop to a region, deterministically
+//! decimate a dense cloud, or (from `gj-splat`, which has the `Camera` type) frustum-cull. Every
+//! selector returns Gaussian indices, which [`GaussianCloud::subset`] turns into a standalone
+//! cloud copying all parallel arrays, including SH rows.
+
+use crate::bounding_box::BoundingBox;
+use crate::gaussian_cloud::GaussianCloud;
+
+/// splitmix64: a small, dependency-free, deterministic PRNG. Good enough for reproducible
+/// decimation; not intended for anything security-sensitive.
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E3779B97F4A7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+    z ^ (z >> 31)
+}
+
+impl GaussianCloud {
+    /// Indices of Gaussians whose position falls inside `region` (inclusive of the bounds).
+    pub fn select_in_box(&self, region: &BoundingBox) -> Vec<u32> {
+        (0..self.count as u32)
+            .filter(|&i| {
+                let p = self.positions[i as usize];
+                (0..3).all(|axis| p[axis] >= region.min[axis] && p[axis] <= region.max[axis])
+            })
+            .collect()
+    }
+
+    /// Deterministically keep roughly `fraction` of Gaussians (clamped to `[0, 1]`), for a fixed
+    /// `seed`. Same cloud + fraction + seed always produces the same subset.
+    pub fn subsample_sparse(&self, fraction: f32, seed: u64) -> GaussianCloud {
+        let fraction = fraction.clamp(0.0, 1.0) as f64;
+        let mut state = seed;
+
+        let indices: Vec<u32> = (0..self.count as u32)
+            .filter(|_| {
+                let roll = (splitmix64(&mut state) >> 11) as f64 / (1u64 << 53) as f64;
+                roll < fraction
+            })
+            .collect();
+
+        self.subset(&indices)
+    }
+
+    /// Copy the Gaussians at `indices` (in order, duplicates allowed) into a new, standalone
+    /// cloud, carrying over SH rows where present.
+    pub fn subset(&self, indices: &[u32]) -> GaussianCloud {
+        let mut cloud = GaussianCloud::with_capacity(indices.len());
+
+        for &i in indices {
+            let idx = i as usize;
+            match self.sh_coefficients.as_ref().map(|sh| &sh[idx]) {
+                Some(sh) if !sh.is_empty() => {
+                    cloud.add_gaussian_with_sh(
+                        self.positions[idx],
+                        self.scales[idx],
+                        self.rotations[idx],
+                        sh.clone(),
+                        self.opacity[idx],
+                    );
+                }
+                _ => {
+                    cloud.add_gaussian(
+                        self.positions[idx],
+                        self.scales[idx],
+                        self.rotations[idx],
+                        self.colors[idx],
+                        self.opacity[idx],
+                    );
+                }
+            }
+        }
+
+        cloud
+    }
+}
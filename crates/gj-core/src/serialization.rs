@@ -0,0 +1,123 @@
+//! Compact binary serialization for [`GaussianCloud`], for fast round-trips of large clouds
+//! without the size/parse overhead of PLY. Layout is a [flexbuffers](https://docs.rs/flexbuffers)
+//! buffer (schema-less, zero-copy friendly) deflated with `flate2`. By convention callers name
+//! the file `.gcloud` for a full-precision cloud or `.splat.gz` when quantized, but the format is
+//! the same either way — [`GaussianCloud::load_compressed`] doesn't inspect the extension.
+
+use std::io::{Read, Write};
+use std::path::Path;
+
+use flate2::read::DeflateDecoder;
+use flate2::write::DeflateEncoder;
+use flate2::Compression;
+use half::f16;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Error, Result};
+use crate::gaussian_cloud::GaussianCloud;
+
+/// Positions/scales can be stored at full `f32` precision or quantized to half precision to
+/// roughly halve their share of the serialized size.
+#[derive(Serialize, Deserialize)]
+enum Vec3Data {
+    Full(Vec<[f32; 3]>),
+    Half(Vec<[u16; 3]>),
+}
+
+impl Vec3Data {
+    fn new(values: &[[f32; 3]], quantize: bool) -> Self {
+        if quantize {
+            Self::Half(
+                values
+                    .iter()
+                    .map(|v| [
+                        f16::from_f32(v[0]).to_bits(),
+                        f16::from_f32(v[1]).to_bits(),
+                        f16::from_f32(v[2]).to_bits(),
+                    ])
+                    .collect(),
+            )
+        } else {
+            Self::Full(values.to_vec())
+        }
+    }
+
+    fn into_f32(self) -> Vec<[f32; 3]> {
+        match self {
+            Self::Full(v) => v,
+            Self::Half(v) => v
+                .into_iter()
+                .map(|h| [
+                    f16::from_bits(h[0]).to_f32(),
+                    f16::from_bits(h[1]).to_f32(),
+                    f16::from_bits(h[2]).to_f32(),
+                ])
+                .collect(),
+        }
+    }
+}
+
+/// On-disk schema for a compressed [`GaussianCloud`]. Kept separate from `GaussianCloud` itself
+/// so the in-memory type doesn't need to carry `serde` derives just for this one path.
+#[derive(Serialize, Deserialize)]
+struct SerializedCloud {
+    count: usize,
+    positions: Vec3Data,
+    scales: Vec3Data,
+    rotations: Vec<[f32; 4]>,
+    colors: Vec<[f32; 3]>,
+    opacity: Vec<f32>,
+    sh_coefficients: Option<Vec<Vec<f32>>>,
+}
+
+impl GaussianCloud {
+    /// Write this cloud to `path` as a deflate-compressed flexbuffers blob. When `quantize` is
+    /// true, positions and scales are stored as half-precision floats instead of `f32`.
+    pub fn save_compressed<P: AsRef<Path>>(&self, path: P, quantize: bool) -> Result<()> {
+        let serialized = SerializedCloud {
+            count: self.count,
+            positions: Vec3Data::new(&self.positions, quantize),
+            scales: Vec3Data::new(&self.scales, quantize),
+            rotations: self.rotations.clone(),
+            colors: self.colors.clone(),
+            opacity: self.opacity.clone(),
+            sh_coefficients: self.sh_coefficients.clone(),
+        };
+
+        let mut flex = flexbuffers::FlexbufferSerializer::new();
+        serialized
+            .serialize(&mut flex)
+            .map_err(|e| Error::SerializationError(e.to_string()))?;
+
+        let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(flex.view())?;
+        let compressed = encoder.finish()?;
+
+        std::fs::write(path, compressed)?;
+        Ok(())
+    }
+
+    /// Load a cloud previously written with [`Self::save_compressed`].
+    pub fn load_compressed<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let compressed = std::fs::read(path)?;
+
+        let mut decoder = DeflateDecoder::new(compressed.as_slice());
+        let mut buf = Vec::new();
+        decoder.read_to_end(&mut buf)?;
+
+        let root = flexbuffers::Reader::get_root(buf.as_slice())
+            .map_err(|e| Error::SerializationError(e.to_string()))?;
+        let serialized = SerializedCloud::deserialize(root)
+            .map_err(|e| Error::SerializationError(e.to_string()))?;
+
+        Ok(GaussianCloud {
+            count: serialized.count,
+            positions: serialized.positions.into_f32(),
+            scales: serialized.scales.into_f32(),
+            rotations: serialized.rotations,
+            colors: serialized.colors,
+            opacity: serialized.opacity,
+            sh_coefficients: serialized.sh_coefficients,
+        })
+    }
+}
@@ -43,6 +43,175 @@ mod tests {
         assert!(ply.starts_with(b"ply\n"));
     }
 
+    #[test]
+    fn test_sh_color_round_trip_through_ply() {
+        let mut cloud = GaussianCloud::new();
+        let sh_coeffs = vec![
+            0.5, 0.3, 0.1, // DC
+            0.1, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, // band 1
+        ];
+        cloud.add_gaussian_with_sh([0.0; 3], [1.0; 3], [1.0, 0.0, 0.0, 0.0], sh_coeffs, 1.0);
+
+        let ply = cloud.to_ply().unwrap();
+        let header = String::from_utf8_lossy(&ply[..ply.windows(10).position(|w| w == b"end_header").unwrap()]);
+        assert!(header.contains("property float f_dc_0"));
+        assert!(header.contains("property float f_rest_0"));
+
+        let tmp = std::env::temp_dir().join("gj_core_sh_round_trip_test.ply");
+        std::fs::write(&tmp, &ply).unwrap();
+        let loaded = GaussianCloud::from_ply(&tmp).unwrap();
+        std::fs::remove_file(&tmp).ok();
+
+        assert_eq!(loaded.count, 1);
+        let coeffs = loaded.sh_coefficients.unwrap();
+        assert_eq!(coeffs[0].len(), 9);
+        assert!((coeffs[0][0] - 0.5).abs() < 1e-5);
+        assert!((coeffs[0][3] - 0.1).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_ply_activations_round_trip() {
+        let mut cloud = GaussianCloud::new();
+        cloud.add_gaussian([1.0, 2.0, 3.0], [0.02, 0.05, 0.01], [0.7071, 0.7071, 0.0, 0.0], [0.8, 0.2, 0.4], 0.6);
+
+        let ply = cloud.to_ply().unwrap();
+        let tmp = std::env::temp_dir().join("gj_core_activations_round_trip_test.ply");
+        std::fs::write(&tmp, &ply).unwrap();
+        let loaded = GaussianCloud::from_ply(&tmp).unwrap();
+        std::fs::remove_file(&tmp).ok();
+
+        assert_eq!(loaded.count, 1);
+        for axis in 0..3 {
+            assert!((loaded.scales[0][axis] - cloud.scales[0][axis]).abs() < 1e-4);
+        }
+        assert!((loaded.opacity[0] - cloud.opacity[0]).abs() < 1e-4);
+
+        let rot_norm: f32 = loaded.rotations[0].iter().map(|v| v * v).sum::<f32>().sqrt();
+        assert!((rot_norm - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_select_in_box_keeps_only_points_inside() {
+        let mut cloud = GaussianCloud::new();
+        cloud.add_gaussian([0.0, 0.0, 0.0], [1.0; 3], [1.0, 0.0, 0.0, 0.0], [1.0; 3], 1.0);
+        cloud.add_gaussian([5.0, 5.0, 5.0], [1.0; 3], [1.0, 0.0, 0.0, 0.0], [1.0; 3], 1.0);
+
+        let region = crate::bounding_box::BoundingBox { min: [-1.0; 3], max: [1.0; 3] };
+        let selected = cloud.select_in_box(&region);
+
+        assert_eq!(selected, vec![0]);
+    }
+
+    #[test]
+    fn test_subsample_sparse_is_deterministic() {
+        let mut cloud = GaussianCloud::new();
+        for i in 0..100 {
+            cloud.add_gaussian([i as f32, 0.0, 0.0], [1.0; 3], [1.0, 0.0, 0.0, 0.0], [1.0; 3], 1.0);
+        }
+
+        let a = cloud.subsample_sparse(0.3, 42);
+        let b = cloud.subsample_sparse(0.3, 42);
+        assert_eq!(a.count, b.count);
+        assert_eq!(a.positions, b.positions);
+        assert!(a.count < cloud.count);
+    }
+
+    #[test]
+    fn test_subset_copies_sh_rows() {
+        let mut cloud = GaussianCloud::new();
+        cloud.add_gaussian_with_sh([0.0; 3], [1.0; 3], [1.0, 0.0, 0.0, 0.0], vec![0.5, 0.3, 0.1], 1.0);
+        cloud.add_gaussian_with_sh([1.0; 3], [1.0; 3], [1.0, 0.0, 0.0, 0.0], vec![0.2, 0.2, 0.2], 1.0);
+
+        let subset = cloud.subset(&[1]);
+        assert_eq!(subset.count, 1);
+        assert_eq!(subset.sh_coefficients.unwrap()[0], vec![0.2, 0.2, 0.2]);
+    }
+
+    #[test]
+    fn test_bvh_query_box_matches_linear_scan() {
+        let mut cloud = GaussianCloud::new();
+        for i in 0..40 {
+            cloud.add_gaussian([i as f32, 0.0, 0.0], [0.01; 3], [1.0, 0.0, 0.0, 0.0], [1.0; 3], 1.0);
+        }
+
+        let region = crate::bounding_box::BoundingBox { min: [9.5, -1.0, -1.0], max: [20.5, 1.0, 1.0] };
+        let bvh = cloud.build_bvh();
+
+        let mut expected = cloud.select_in_box(&region);
+        let mut actual = bvh.query_box(&region);
+        expected.sort_unstable();
+        actual.sort_unstable();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_bvh_raycast_hits_nearest_gaussian() {
+        let mut cloud = GaussianCloud::new();
+        cloud.add_gaussian([0.0, 0.0, 5.0], [0.2; 3], [1.0, 0.0, 0.0, 0.0], [1.0; 3], 1.0);
+        cloud.add_gaussian([0.0, 0.0, 10.0], [0.2; 3], [1.0, 0.0, 0.0, 0.0], [1.0; 3], 1.0);
+
+        let bvh = cloud.build_bvh();
+        let hit = bvh.raycast([0.0, 0.0, 0.0], [0.0, 0.0, 1.0]);
+
+        assert_eq!(hit, Some(0));
+    }
+
+    #[test]
+    fn test_bvh_raycast_misses_when_ray_points_away() {
+        let mut cloud = GaussianCloud::new();
+        cloud.add_gaussian([0.0, 0.0, 5.0], [0.2; 3], [1.0, 0.0, 0.0, 0.0], [1.0; 3], 1.0);
+
+        let bvh = cloud.build_bvh();
+        let hit = bvh.raycast([0.0, 0.0, 0.0], [0.0, 0.0, -1.0]);
+
+        assert_eq!(hit, None);
+    }
+
+    #[test]
+    fn test_morph_halfway_blends_position_and_opacity() {
+        let mut a = GaussianCloud::new();
+        a.add_gaussian([0.0; 3], [1.0; 3], [1.0, 0.0, 0.0, 0.0], [0.0; 3], 0.0);
+
+        let mut b = GaussianCloud::new();
+        b.add_gaussian([2.0, 2.0, 2.0], [1.0; 3], [1.0, 0.0, 0.0, 0.0], [1.0; 3], 1.0);
+
+        let mid = a.morph(&b, 0.5);
+        assert_eq!(mid.count, 1);
+        assert_eq!(mid.positions[0], [1.0, 1.0, 1.0]);
+        assert!((mid.opacity[0] - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_morph_pads_smaller_cloud_with_zero_opacity() {
+        let mut a = GaussianCloud::new();
+        a.add_gaussian([0.0; 3], [1.0; 3], [1.0, 0.0, 0.0, 0.0], [1.0; 3], 1.0);
+
+        let mut b = GaussianCloud::new();
+        b.add_gaussian([0.0; 3], [1.0; 3], [1.0, 0.0, 0.0, 0.0], [1.0; 3], 1.0);
+        b.add_gaussian([5.0; 3], [1.0; 3], [1.0, 0.0, 0.0, 0.0], [1.0; 3], 1.0);
+
+        let morphed = a.morph(&b, 0.0);
+        assert_eq!(morphed.count, 2);
+        assert_eq!(morphed.opacity[1], 0.0);
+    }
+
+    #[test]
+    fn test_morph_slerp_endpoints_match_inputs() {
+        let mut a = GaussianCloud::new();
+        a.add_gaussian([0.0; 3], [1.0; 3], [1.0, 0.0, 0.0, 0.0], [1.0; 3], 1.0);
+
+        let mut b = GaussianCloud::new();
+        b.add_gaussian([0.0; 3], [1.0; 3], [0.7071, 0.7071, 0.0, 0.0], [1.0; 3], 1.0);
+
+        let start = a.morph(&b, 0.0);
+        let end = a.morph(&b, 1.0);
+
+        for axis in 0..4 {
+            assert!((start.rotations[0][axis] - a.rotations[0][axis]).abs() < 1e-4);
+            assert!((end.rotations[0][axis] - b.rotations[0][axis]).abs() < 1e-3);
+        }
+    }
+
     #[test]
     fn test_pipeline_config() {
         let config = PipelineConfig::lgm_default();
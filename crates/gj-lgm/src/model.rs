@@ -2,7 +2,8 @@ use burn::nn::conv::{Conv2d, Conv2dConfig};
 use burn::nn::PaddingConfig2d;
 use burn::prelude::{Backend, Device, Module};
 use burn::Tensor;
-use burn::tensor::activation::relu;
+use burn::tensor::activation::{relu, sigmoid, softplus, tanh};
+use crate::preprocessing::VIEW_CHANNELS;
 
 /// LGM Model - Multi-view images to 3D Gaussians
 #[derive(Module, Debug)]
@@ -17,7 +18,10 @@ pub struct LGMModel<B: Backend> {
 impl<B: Backend> LGMModel<B> {
     pub fn new(device: &Device<B>) -> Self {
         Self {
-            conv_in: Conv2dConfig::new([9, 64], [3, 3])
+            // `VIEW_CHANNELS` in: RGB + Plücker ray + the per-pixel depth channel
+            // `preprocess_images_with_depth` fills (zeroed when a view has no real depth), so the
+            // forward pass can use measured depth directly instead of only seeing it post-hoc.
+            conv_in: Conv2dConfig::new([VIEW_CHANNELS, 64], [3, 3])
                 .with_padding(PaddingConfig2d::Explicit(1, 1))
                 .init(device),
             conv_out: Conv2dConfig::new([64, 14], [1, 1])
@@ -25,13 +29,13 @@ impl<B: Backend> LGMModel<B> {
         }
     }
 
-    /// Forward pass: [B, 4, 9, H, W] -> [B, N, 14]
+    /// Forward pass: [B, 4, VIEW_CHANNELS, H, W] -> [B, N, 14]
     pub fn forward(&self, images: Tensor<B, 5>) -> Tensor<B, 3> {
         let dims = images.dims();
-        let (b, num_views, _channels, h, w) = (dims[0], dims[1], dims[2], dims[3], dims[4]);
+        let (b, num_views, channels, h, w) = (dims[0], dims[1], dims[2], dims[3], dims[4]);
 
-        // Reshape: [B, 4, 9, H, W] -> [B*4, 9, H, W]
-        let x = images.reshape([b * num_views, 9, h, w]);
+        // Reshape: [B, 4, VIEW_CHANNELS, H, W] -> [B*4, VIEW_CHANNELS, H, W]
+        let x = images.reshape([b * num_views, channels, h, w]);
 
         // Simple processing
         let x = self.conv_in.forward(x);
@@ -47,60 +51,33 @@ impl<B: Backend> LGMModel<B> {
         self.apply_activations(x)
     }
 
+    /// Apply per-channel activations to the raw `[B, N, 14]` Gaussian parameters, entirely on the
+    /// backend device: no `into_data()`/host round-trip, so this scales with `N` the same way the
+    /// rest of the forward pass does.
     pub(crate) fn apply_activations(&self, x: Tensor<B, 3>) -> Tensor<B, 3> {
-        let device = x.device();
         let dims = x.dims();
         let (batch, n, _params) = (dims[0], dims[1], dims[2]);
+        let all = 0..batch;
+        let all_n = 0..n;
 
-        // Extract data using into_data()
-        let data = x.into_data();
-        let values: Vec<f32> = data.iter::<f32>().collect();
+        // Position [0:3] - clamp to [-1, 1]
+        let position = x.clone().slice([all.clone(), all_n.clone(), 0..3]).clamp(-1.0, 1.0);
 
-        // Process each Gaussian
-        let mut output = vec![0.0f32; batch * n * 14];
+        // Opacity [3:4] - sigmoid
+        let opacity = sigmoid(x.clone().slice([all.clone(), all_n.clone(), 3..4]));
 
-        for b in 0..batch {
-            for i in 0..n {
-                let base_in = (b * n + i) * 14;
-                let base_out = base_in;
+        // Scale [4:7] - softplus * 0.1
+        let scale = softplus(x.clone().slice([all.clone(), all_n.clone(), 4..7]), 1.0).mul_scalar(0.1);
 
-                // Position [0:3] - clamp to [-1, 1]
-                for j in 0..3 {
-                    output[base_out + j] = values[base_in + j].clamp(-1.0, 1.0);
-                }
+        // Rotation [7:11] - normalize quaternion, guarding against a near-zero norm the same way
+        // the old CPU loop did.
+        let quat = x.clone().slice([all.clone(), all_n.clone(), 7..11]);
+        let quat_norm = quat.clone().powf_scalar(2.0).sum_dim(2).sqrt().clamp_min(1e-8);
+        let rotation = quat.div(quat_norm);
 
-                // Opacity [3] - sigmoid
-                let opacity = values[base_in + 3];
-                output[base_out + 3] = 1.0 / (1.0 + (-opacity).exp());
+        // RGB [11:14] - tanh * 0.5 + 0.5
+        let color = tanh(x.slice([all, all_n, 11..14])).mul_scalar(0.5).add_scalar(0.5);
 
-                // Scale [4:7] - softplus * 0.1
-                for j in 4..7 {
-                    let val = values[base_in + j];
-                    output[base_out + j] = (1.0 + val.exp()).ln() * 0.1;
-                }
-
-                // Rotation [7:11] - normalize quaternion
-                let mut quat = [0.0f32; 4];
-                for j in 0..4 {
-                    quat[j] = values[base_in + 7 + j];
-                }
-                let norm = (quat[0]*quat[0] + quat[1]*quat[1] +
-                    quat[2]*quat[2] + quat[3]*quat[3]).sqrt();
-                let norm = if norm > 1e-8 { norm } else { 1.0 };
-                for j in 0..4 {
-                    output[base_out + 7 + j] = quat[j] / norm;
-                }
-
-                // RGB [11:14] - tanh * 0.5 + 0.5
-                for j in 11..14 {
-                    let val = values[base_in + j];
-                    output[base_out + j] = val.tanh() * 0.5 + 0.5;
-                }
-            }
-        }
-
-        let total_elements = batch * n * 14;
-        let tensor_1d: Tensor<B, 1> = Tensor::from_floats(output.as_slice(), &device);
-        tensor_1d.reshape([batch, n, 14])
+        Tensor::cat(vec![position, opacity, scale, rotation, color], 2)
     }
 }
\ No newline at end of file
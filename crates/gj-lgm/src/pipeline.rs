@@ -5,7 +5,11 @@
 use burn::tensor::{backend::Backend, Device};
 use image::RgbaImage;
 use crate::model::LGMModel;
-use crate::preprocessing::{CameraInfo, preprocess_images, tensor_to_gaussian_cloud};
+use crate::preprocessing::{
+    CameraInfo, DepthMap, fuse_depth_points, preprocess_images, preprocess_images_with_depth,
+    tensor_to_gaussian_cloud, tensor_to_gaussian_cloud_seeded,
+};
+use crate::rig::{resample_to, CameraRig, MODEL_VIEW_COUNT};
 use gj_core::error::{Error, Result};
 use gj_core::gaussian_cloud::GaussianCloud;
 
@@ -43,7 +47,8 @@ impl<B: Backend> LGMPipeline<B> {
         tensor_to_gaussian_cloud(output)
     }
 
-    /// Generate with custom camera positions
+    /// Generate with custom camera positions. Any view count is accepted: the image/camera
+    /// stack is resampled (tiled or dropped) to [`MODEL_VIEW_COUNT`] before inference.
     pub fn generate_with_cameras(
         &self,
         images: &[RgbaImage],
@@ -56,20 +61,76 @@ impl<B: Backend> LGMPipeline<B> {
             ));
         }
 
+        if images.is_empty() {
+            return Err(Error::InvalidConfig("At least one view is required".to_string()));
+        }
+
+        let images = resample_to(images, MODEL_VIEW_COUNT);
+        let cameras = resample_to(cameras, MODEL_VIEW_COUNT);
+
+        // Preprocess
+        let input = preprocess_images(&images, &cameras, &self.device)?;
+
+        // Run model
+        let output = self.model.forward(input);
+
+        // Convert to cloud
+        tensor_to_gaussian_cloud(output)
+    }
+
+    /// Generate from a named capture rig (e.g. a 6-view cube or an 8-view ring) instead of
+    /// hand-rolled [`CameraInfo`] poses. `images` must supply one image per rig view; rigs whose
+    /// view count doesn't match [`MODEL_VIEW_COUNT`] are resampled like [`Self::generate_with_cameras`].
+    pub fn generate_with_rig(&self, images: &[RgbaImage], rig: &CameraRig) -> Result<GaussianCloud> {
+        let cameras = rig.cameras();
+
+        if images.len() != cameras.len() {
+            return Err(Error::InvalidConfig(format!(
+                "Rig expects {} views, got {} images", cameras.len(), images.len()
+            )));
+        }
+
+        self.generate_with_cameras(images, &cameras)
+    }
+
+    /// Generate from RGB-D / multi-view-stereo views: alongside each image, an optional depth
+    /// map and its camera's intrinsics. Each view's depth is embedded as an extra input channel
+    /// (see [`preprocess_images_with_depth`]) so the model's forward pass sees real measured
+    /// geometry directly, rather than relying purely on the learned RGB-only estimate; the
+    /// depth points are also fused across views and used to snap each predicted Gaussian mean to
+    /// its nearest measurement afterward, as a correction against whatever the (still
+    /// RGB-dominated) prediction drifted to.
+    pub fn generate_with_depth(
+        &self,
+        images: &[RgbaImage],
+        depths: &[Option<DepthMap>],
+        cameras: &[CameraInfo],
+    ) -> Result<GaussianCloud> {
+        if images.len() != cameras.len() || images.len() != depths.len() {
+            return Err(Error::InvalidConfig(format!(
+                "Images ({}), depths ({}) and cameras ({}) count mismatch",
+                images.len(), depths.len(), cameras.len()
+            )));
+        }
+
         if images.len() != 4 {
             return Err(Error::InvalidConfig(
                 "LGM requires exactly 4 views".to_string()
             ));
         }
 
-        // Preprocess
-        let input = preprocess_images(&images, cameras, &self.device)?;
+        // Preprocess RGB + camera features + per-view depth, so the forward pass itself sees
+        // depth instead of only the post-hoc seed below.
+        let input = preprocess_images_with_depth(&images, cameras, Some(depths), &self.device)?;
+
+        // Fuse back-projected depth points into a world-space seed for the positions
+        let seed = fuse_depth_points(depths, cameras);
 
         // Run model
         let output = self.model.forward(input);
 
-        // Convert to cloud
-        tensor_to_gaussian_cloud(output)
+        // Convert to cloud, snapping predicted positions to the depth seed when present
+        tensor_to_gaussian_cloud_seeded(output, Some(&seed))
     }
 }
 
@@ -104,6 +165,29 @@ mod tests {
         assert!(cloud.count > 0);
     }
 
+    #[test]
+    fn test_generate_with_rig_six_view_cube() {
+        let device = Default::default();
+        let pipeline = LGMPipeline::<TestBackend>::new(device);
+
+        let images: Vec<RgbaImage> = (0..6).map(|_| RgbaImage::new(256, 256)).collect();
+        let result = pipeline.generate_with_rig(&images, &CameraRig::SixViewCube);
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_generate_with_cameras_resamples_odd_view_counts() {
+        let device = Default::default();
+        let pipeline = LGMPipeline::<TestBackend>::new(device);
+
+        let images: Vec<RgbaImage> = (0..2).map(|_| RgbaImage::new(256, 256)).collect();
+        let cameras = CameraRig::OrbitN(2).cameras();
+        let result = pipeline.generate_with_cameras(&images, &cameras);
+
+        assert!(result.is_ok());
+    }
+
     #[test]
     fn test_wrong_image_count() {
         let device = Default::default();
@@ -1,7 +1,47 @@
 use burn::tensor::{backend::Backend, Device, Tensor, Shape};
 use image::{RgbaImage, DynamicImage, ImageBuffer, Rgba};
+use rayon::prelude::*;
 use gj_core::error::Error;
-use gj_core::gaussian_cloud::GaussianCloud;
+use gj_core::gaussian_cloud::{sh_num_terms, GaussianCloud};
+
+/// Explicit extrinsic pose (world-from-camera), for views that aren't just an orbit around the
+/// origin — e.g. a real photo set or a capture rig with arbitrary placement. When present on a
+/// [`CameraInfo`], it takes precedence over the `azimuth`/`elevation`/`radius` orbit fields.
+#[derive(Clone, Debug)]
+pub struct CameraPose {
+    /// Camera position in world space.
+    pub position: [f32; 3],
+    /// Camera-space right/up/forward basis vectors, expressed in world space.
+    pub right: [f32; 3],
+    pub up: [f32; 3],
+    pub forward: [f32; 3],
+}
+
+impl CameraPose {
+    /// Build a pose from a rotation matrix (columns = right/up/forward, world-from-camera) and
+    /// a world-space translation.
+    pub fn from_rotation_translation(rotation: [[f32; 3]; 3], translation: [f32; 3]) -> Self {
+        Self {
+            position: translation,
+            right: [rotation[0][0], rotation[1][0], rotation[2][0]],
+            up: [rotation[0][1], rotation[1][1], rotation[2][1]],
+            forward: [rotation[0][2], rotation[1][2], rotation[2][2]],
+        }
+    }
+
+    /// Build a pose from a 4x4 world-from-camera view matrix, stored row-major with the
+    /// rotation in the upper-left 3x3 block (columns = right/up/forward) and the translation in
+    /// the rightmost column.
+    pub fn from_view_matrix(matrix: [[f32; 4]; 4]) -> Self {
+        let rotation = [
+            [matrix[0][0], matrix[0][1], matrix[0][2]],
+            [matrix[1][0], matrix[1][1], matrix[1][2]],
+            [matrix[2][0], matrix[2][1], matrix[2][2]],
+        ];
+        let translation = [matrix[0][3], matrix[1][3], matrix[2][3]];
+        Self::from_rotation_translation(rotation, translation)
+    }
+}
 
 /// Camera information for a view
 #[derive(Clone, Debug)]
@@ -9,87 +49,294 @@ pub struct CameraInfo {
     pub azimuth: f32,
     pub elevation: f32,
     pub radius: f32,
+
+    /// Explicit extrinsic pose, for views not describable as an orbit around the origin. When
+    /// `Some`, overrides `azimuth`/`elevation`/`radius`.
+    pub pose: Option<CameraPose>,
+
+    /// Focal lengths (pixels) for RGB-D/MVS views that carry real depth.
+    pub fx: f32,
+    pub fy: f32,
+    /// Principal point (pixels) for RGB-D/MVS views that carry real depth.
+    pub cx: f32,
+    pub cy: f32,
+    /// Scale applied to raw depth samples to convert them to world units.
+    pub depth_scale: f32,
 }
 
 impl CameraInfo {
     pub fn default_4view() -> [CameraInfo; 4] {
         [
-            CameraInfo { azimuth: 0.0, elevation: 0.0, radius: 2.0 },
-            CameraInfo { azimuth: 90.0, elevation: 0.0, radius: 2.0 },
-            CameraInfo { azimuth: 180.0, elevation: 0.0, radius: 2.0 },
-            CameraInfo { azimuth: 270.0, elevation: 0.0, radius: 2.0 },
+            CameraInfo { azimuth: 0.0, elevation: 0.0, radius: 2.0, pose: None, fx: 256.0, fy: 256.0, cx: 128.0, cy: 128.0, depth_scale: 1.0 },
+            CameraInfo { azimuth: 90.0, elevation: 0.0, radius: 2.0, pose: None, fx: 256.0, fy: 256.0, cx: 128.0, cy: 128.0, depth_scale: 1.0 },
+            CameraInfo { azimuth: 180.0, elevation: 0.0, radius: 2.0, pose: None, fx: 256.0, fy: 256.0, cx: 128.0, cy: 128.0, depth_scale: 1.0 },
+            CameraInfo { azimuth: 270.0, elevation: 0.0, radius: 2.0, pose: None, fx: 256.0, fy: 256.0, cx: 128.0, cy: 128.0, depth_scale: 1.0 },
         ]
     }
 
-    pub fn to_features(&self) -> [f32; 6] {
+    /// Build a view from an explicit extrinsic pose (real photo sets, non-orbit rigs) instead of
+    /// an azimuth/elevation/radius orbit description.
+    pub fn from_pose(pose: CameraPose, fx: f32, fy: f32, cx: f32, cy: f32, depth_scale: f32) -> Self {
+        Self { azimuth: 0.0, elevation: 0.0, radius: 0.0, pose: Some(pose), fx, fy, cx, cy, depth_scale }
+    }
+
+    /// World-space position of this view's camera: the explicit pose's position when set,
+    /// otherwise derived from the azimuth/elevation/radius orbit around the origin.
+    fn position(&self) -> [f32; 3] {
+        if let Some(pose) = &self.pose {
+            return pose.position;
+        }
+
         let az = self.azimuth.to_radians();
         let el = self.elevation.to_radians();
         [
-            az.sin(),
-            az.cos(),
-            el.sin(),
-            el.cos(),
-            self.radius / 5.0,
-            (self.radius / 5.0).powi(2),
+            self.radius * el.cos() * az.sin(),
+            self.radius * el.sin(),
+            self.radius * el.cos() * az.cos(),
         ]
     }
+
+    /// The camera's right/up/forward basis vectors: the explicit pose's basis when set,
+    /// otherwise derived by looking at the origin from the orbit position.
+    fn basis(&self) -> ([f32; 3], [f32; 3], [f32; 3]) {
+        if let Some(pose) = &self.pose {
+            return (pose.right, pose.up, pose.forward);
+        }
+
+        let position = self.position();
+        let forward = normalize([-position[0], -position[1], -position[2]]);
+        let world_up = [0.0, 1.0, 0.0];
+        let right = normalize(cross(forward, world_up));
+        let up = cross(right, forward);
+        (right, up, forward)
+    }
+
+    /// Per-pixel Plücker ray through pixel `(px, py)`: a normalized world-space `direction` and
+    /// its `moment = origin × direction`, which together encode both where the ray points and
+    /// where it passes through space. This is the standard per-pixel camera conditioning used by
+    /// large multi-view reconstruction models (LGM, GS-LRM, LVSM, ...), in place of a single
+    /// feature vector constant across the whole image.
+    pub fn plucker_ray(&self, px: u32, py: u32) -> ([f32; 3], [f32; 3]) {
+        let (right, up, forward) = self.basis();
+        let origin = self.position();
+
+        let cam_x = (px as f32 - self.cx) / self.fx;
+        let cam_y = (py as f32 - self.cy) / self.fy;
+
+        let direction = normalize([
+            right[0] * cam_x - up[0] * cam_y + forward[0],
+            right[1] * cam_x - up[1] * cam_y + forward[1],
+            right[2] * cam_x - up[2] * cam_y + forward[2],
+        ]);
+
+        let moment = cross(origin, direction);
+
+        (direction, moment)
+    }
+
+    /// Unproject a per-pixel depth map into world-space points using this view's intrinsics
+    /// and pose: `p = depth * K^-1 * [u, v, 1]`, transformed by the camera's inverse view.
+    pub fn unproject_depth(&self, depth: &DepthMap) -> Vec<[f32; 3]> {
+        let (right, up, forward) = self.basis();
+        let position = self.position();
+
+        let mut points = Vec::new();
+        for y in 0..depth.height {
+            for x in 0..depth.width {
+                let raw = depth.samples[(y * depth.width + x) as usize];
+                if !raw.is_finite() || raw <= 0.0 {
+                    continue;
+                }
+                let z = raw * self.depth_scale;
+
+                // Camera-space ray direction through pixel (x, y) via K^-1 * [u, v, 1].
+                let cam_x = (x as f32 - self.cx) / self.fx;
+                let cam_y = (y as f32 - self.cy) / self.fy;
+
+                // Camera looks down +forward; image y grows downward so flip it to camera-up.
+                let point = [
+                    position[0] + right[0] * cam_x * z - up[0] * cam_y * z + forward[0] * z,
+                    position[1] + right[1] * cam_x * z - up[1] * cam_y * z + forward[1] * z,
+                    position[2] + right[2] * cam_x * z - up[2] * cam_y * z + forward[2] * z,
+                ];
+                points.push(point);
+            }
+        }
+        points
+    }
+}
+
+/// Per-pixel depth map accompanying an RGB-D / multi-view-stereo image.
+#[derive(Clone, Debug)]
+pub struct DepthMap {
+    pub samples: Vec<f32>,
+    pub width: u32,
+    pub height: u32,
+}
+
+fn cross(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn normalize(a: [f32; 3]) -> [f32; 3] {
+    let len = (a[0] * a[0] + a[1] * a[1] + a[2] * a[2]).sqrt();
+    if len > 1e-8 {
+        [a[0] / len, a[1] / len, a[2] / len]
+    } else {
+        a
+    }
+}
+
+/// Fuse back-projected depth points from any number of views into a single world-space
+/// point set, to seed/anchor the Gaussian means before the model refines them. Each view's
+/// back-projection is independent, so views are unprojected in parallel across CPU cores and
+/// concatenated in view order.
+pub fn fuse_depth_points(
+    depths: &[Option<DepthMap>],
+    cameras: &[CameraInfo],
+) -> Vec<[f32; 3]> {
+    depths
+        .par_iter()
+        .zip(cameras.par_iter())
+        .filter_map(|(depth, camera)| depth.as_ref().map(|depth| camera.unproject_depth(depth)))
+        .flatten()
+        .collect()
 }
 
-/// Preprocess images to tensor
+/// Per-view channel count: RGB(3) + Plücker direction(3) + moment(3) + depth(1). The depth
+/// channel is zero-filled for views with no [`DepthMap`] (and for every view when `depths` is
+/// omitted entirely), so [`crate::model::LGMModel`] always sees the same input shape whether or
+/// not real depth is available.
+pub const VIEW_CHANNELS: usize = 10;
+
+/// Preprocess images to tensor. Accepts any number of views, as long as `images` and `cameras`
+/// agree in length; callers that need a fixed view count (e.g. to match the model's trained
+/// view dimension) should resample beforehand via [`crate::rig::resample_to`]. Each camera's
+/// pose (orbit-derived or an explicit [`CameraPose`]) is embedded per-pixel as a Plücker ray,
+/// so arbitrary capture rigs and real photo sets work, not just the orbit cameras this crate
+/// generates.
 pub fn preprocess_images<B: Backend>(
     images: &[RgbaImage],
     cameras: &[CameraInfo],
     device: &Device<B>,
 ) -> gj_core::error::Result<Tensor<B, 5>> {
-    if images.len() != 4 || cameras.len() != 4 {
+    preprocess_images_with_depth(images, cameras, None, device)
+}
+
+/// Like [`preprocess_images`], but also embeds each view's own depth map (when present) as a
+/// 10th per-pixel channel, so the model's forward pass can use real measured depth directly
+/// instead of the caller anchoring its predictions after the fact. `depths`, when `Some`, must
+/// be the same length as `images`; a `None` entry within it (or omitting `depths` entirely)
+/// leaves that view's depth channel at zero.
+pub fn preprocess_images_with_depth<B: Backend>(
+    images: &[RgbaImage],
+    cameras: &[CameraInfo],
+    depths: Option<&[Option<DepthMap>]>,
+    device: &Device<B>,
+) -> gj_core::error::Result<Tensor<B, 5>> {
+    if images.is_empty() || images.len() != cameras.len() {
         return Err(Error::InvalidConfig(
-            "Expected 4 images and 4 cameras".to_string()
+            format!("Images ({}) and cameras ({}) count mismatch", images.len(), cameras.len())
         ));
     }
+    if let Some(depths) = depths {
+        if depths.len() != images.len() {
+            return Err(Error::InvalidConfig(
+                format!("Images ({}) and depths ({}) count mismatch", images.len(), depths.len())
+            ));
+        }
+    }
 
-    let mut all_data = Vec::new();
+    let num_views = images.len();
+
+    // Each view's resize + RGB extraction + Plücker/depth embedding is independent of every
+    // other view, so run them across CPU cores and stitch the per-view chunks back together in
+    // view order afterwards (the model's `B*num_views` reshape in `LGMModel::forward` depends on
+    // this order matching `images`/`cameras`).
+    let per_view: Vec<Vec<f32>> = images
+        .par_iter()
+        .zip(cameras.par_iter())
+        .enumerate()
+        .map(|(i, (img, camera))| {
+            // Resize to 256x256
+            let img = image::imageops::resize(
+                img,
+                256,
+                256,
+                image::imageops::FilterType::Lanczos3
+            );
 
-    for (img, camera) in images.iter().zip(cameras.iter()) {
-        // Resize to 256x256
-        let img = image::imageops::resize(
-            img,
-            256,
-            256,
-            image::imageops::FilterType::Lanczos3
-        );
+            // Convert to tensor data [VIEW_CHANNELS, 256, 256]
+            let mut view_data = vec![0.0f32; VIEW_CHANNELS * 256 * 256];
+
+            // RGB channels
+            for y in 0..256_usize {
+                for x in 0..256_usize {
+                    let pixel = img.get_pixel(x as u32, y as u32);
+                    let idx = y * 256 + x;
+                    view_data[idx] = pixel[0] as f32 / 255.0;                    // R
+                    view_data[256*256 + idx] = pixel[1] as f32 / 255.0;          // G
+                    view_data[2*256*256 + idx] = pixel[2] as f32 / 255.0;        // B
+                }
+            }
 
-        // Convert to tensor data [9, 256, 256]
-        let mut view_data = vec![0.0f32; 9 * 256 * 256];
-
-        // RGB channels
-        for y in 0..256_usize {
-            for x in 0..256_usize {
-                let pixel = img.get_pixel(x as u32, y as u32);
-                let idx = y * 256 + x;
-                view_data[idx] = pixel[0] as f32 / 255.0;                    // R
-                view_data[256*256 + idx] = pixel[1] as f32 / 255.0;          // G
-                view_data[2*256*256 + idx] = pixel[2] as f32 / 255.0;        // B
+            // Per-pixel Plücker ray embedding: direction (channels 3-5), moment (channels 6-8).
+            for y in 0..256_u32 {
+                for x in 0..256_u32 {
+                    let (direction, moment) = camera.plucker_ray(x, y);
+                    let idx = (y as usize) * 256 + x as usize;
+                    for c in 0..3 {
+                        view_data[(3 + c) * 256 * 256 + idx] = direction[c];
+                        view_data[(6 + c) * 256 * 256 + idx] = moment[c];
+                    }
+                }
             }
-        }
 
-        // Camera features (constant across spatial dimensions)
-        let features = camera.to_features();
-        for ch in 0..6 {
-            for i in 0..(256*256) {
-                view_data[(3 + ch) * 256 * 256 + i] = features[ch];
+            // Depth channel (9): real measured depth resampled to 256x256, zero where this view
+            // has none, so the model's forward pass can anchor its own predictions on depth
+            // instead of a post-hoc nearest-point blend.
+            if let Some(depth) = depths.and_then(|d| d[i].as_ref()) {
+                let resized = resize_depth_to_256(depth);
+                let depth_plane = &mut view_data[9 * 256 * 256..10 * 256 * 256];
+                depth_plane.copy_from_slice(&resized);
             }
-        }
 
+            view_data
+        })
+        .collect();
+
+    let mut all_data = Vec::with_capacity(num_views * VIEW_CHANNELS * 256 * 256);
+    for view_data in per_view {
         all_data.extend_from_slice(&view_data);
     }
 
-    // Create tensor [1, 4, 9, 256, 256]
+    // Create tensor [1, num_views, VIEW_CHANNELS, 256, 256]
     let tensor = Tensor::<B, 1>::from_floats(all_data.as_slice(), device)
-        .reshape([1, 4, 9, 256, 256]);
+        .reshape([1, num_views, VIEW_CHANNELS, 256, 256]);
 
     Ok(tensor)
 }
 
+/// Nearest-neighbor resample of a raw depth map to 256x256, matching the RGB resize target.
+/// Nearest (not Lanczos3, as RGB uses) avoids blending real depth across discontinuities at
+/// object boundaries into meaningless intermediate values.
+fn resize_depth_to_256(depth: &DepthMap) -> Vec<f32> {
+    let Some(buffer) = image::ImageBuffer::<image::Luma<f32>, Vec<f32>>::from_raw(
+        depth.width,
+        depth.height,
+        depth.samples.clone(),
+    ) else {
+        return vec![0.0f32; 256 * 256];
+    };
+
+    let resized = image::imageops::resize(&buffer, 256, 256, image::imageops::FilterType::Nearest);
+    resized.into_raw()
+}
+
 
 /// Helper: Create dummy multi-view images for testing
 #[cfg(test)]
@@ -115,35 +362,82 @@ pub fn create_dummy_images() -> Vec<RgbaImage> {
         .collect()
 }
 
+/// Number of floats the model emits per Gaussian: position(3) + opacity(1) + scale(3) +
+/// rotation(4) + DC color(3).
+const BASE_PARAMS: usize = 14;
+
 /// Convert tensor to GaussianCloud
 pub fn tensor_to_gaussian_cloud<B: Backend>(tensor: Tensor<B, 3>) -> gj_core::error::Result<GaussianCloud> {
+    tensor_to_gaussian_cloud_seeded(tensor, None)
+}
+
+/// Convert tensor to GaussianCloud, snapping each predicted position to the nearest
+/// back-projected depth point when a seed point set is available (see [`fuse_depth_points`]).
+/// A correction pass, not the primary route depth takes into the pipeline - `generate_with_depth`
+/// feeds the same depth maps into the model's forward pass via
+/// [`preprocess_images_with_depth`]'s 10th channel first.
+pub fn tensor_to_gaussian_cloud_seeded<B: Backend>(
+    tensor: Tensor<B, 3>,
+    depth_seed: Option<&[[f32; 3]]>,
+) -> gj_core::error::Result<GaussianCloud> {
+    tensor_to_gaussian_cloud_with_sh(tensor, depth_seed, 0)
+}
+
+/// A decoded Gaussian row pending insertion into a [`GaussianCloud`], carrying either a plain DC
+/// color or a full SH coefficient vector depending on `sh_degree`. Exists only to move row
+/// decoding (done in parallel) apart from cloud insertion (done in order, since [`GaussianCloud`]
+/// assigns indices sequentially).
+struct GaussianRow {
+    position: [f32; 3],
+    opacity: f32,
+    scale: [f32; 3],
+    rotation: [f32; 4],
+    color_or_sh: ColorOrSh,
+}
+
+enum ColorOrSh {
+    Color([f32; 3]),
+    Sh(Vec<f32>),
+}
+
+/// Convert tensor to GaussianCloud, reading `sh_degree` extra spherical-harmonics bands after
+/// the base 14 params per Gaussian (position, opacity, scale, rotation, DC color). Each row is
+/// `BASE_PARAMS + 3 * (sh_num_terms(sh_degree) - 1)` floats wide, with the rest terms laid out
+/// band-by-band as `(r, g, b)` triples, matching [`GaussianCloud::add_gaussian_with_sh`].
+/// `sh_degree = 0` reproduces the original fixed-width layout.
+pub fn tensor_to_gaussian_cloud_with_sh<B: Backend>(
+    tensor: Tensor<B, 3>,
+    depth_seed: Option<&[[f32; 3]]>,
+    sh_degree: usize,
+) -> gj_core::error::Result<GaussianCloud> {
     let dims = tensor.dims();
     let (_batch, n, _params) = (dims[0], dims[1], dims[2]);
 
     let data = tensor.into_data();
     let values: Vec<f32> = data.iter::<f32>().collect();
 
-    let mut cloud = GaussianCloud::with_capacity(n);
-
-    for i in 0..n {
-        let base = i * 14;
+    let num_rest = 3 * (sh_num_terms(sh_degree) - 1);
+    let stride = BASE_PARAMS + num_rest;
 
-        let position = [
-            values[base],
-            values[base + 1],
-            values[base + 2],
-        ];
+    // Per-row depth-seed lookup (`nearest_point` scans the whole seed set) and unpacking are
+    // independent across Gaussians, so compute them in parallel; only the final `cloud.add_*`
+    // insertion order needs to stay sequential, to keep indices stable and match the scalar path.
+    let rows: Vec<Option<GaussianRow>> = (0..n)
+        .into_par_iter()
+        .map(|i| {
+            let base = i * stride;
+            let mut position = [values[base], values[base + 1], values[base + 2]];
+            let opacity = values[base + 3];
 
-        let opacity = values[base + 3];
+            if opacity <= 0.01 {
+                return None;
+            }
 
-        // Only add if visible
-        if opacity > 0.01 {
-            let scale = [
-                values[base + 4],
-                values[base + 5],
-                values[base + 6],
-            ];
+            if let Some(seed) = depth_seed.filter(|s| !s.is_empty()) {
+                position = nearest_point(seed, position);
+            }
 
+            let scale = [values[base + 4], values[base + 5], values[base + 6]];
             let rotation = [
                 values[base + 7],
                 values[base + 8],
@@ -151,19 +445,61 @@ pub fn tensor_to_gaussian_cloud<B: Backend>(tensor: Tensor<B, 3>) -> gj_core::er
                 values[base + 10],
             ];
 
-            let color = [
-                values[base + 11],
-                values[base + 12],
-                values[base + 13],
-            ];
+            let color_or_sh = if num_rest > 0 {
+                // values[base+11..14] is the raw (unscaled) DC term here, not a final color.
+                let mut sh_coeffs = vec![0.0f32; 3 + num_rest];
+                sh_coeffs[..3].copy_from_slice(&values[base + 11..base + 14]);
+                sh_coeffs[3..].copy_from_slice(&values[base + 14..base + 14 + num_rest]);
+                ColorOrSh::Sh(sh_coeffs)
+            } else {
+                ColorOrSh::Color([values[base + 11], values[base + 12], values[base + 13]])
+            };
+
+            Some(GaussianRow { position, opacity, scale, rotation, color_or_sh })
+        })
+        .collect();
 
-            cloud.add_gaussian(position, scale, rotation, color, opacity);
+    let mut cloud = GaussianCloud::with_capacity(n);
+
+    for row in rows.into_iter().flatten() {
+        match row.color_or_sh {
+            ColorOrSh::Sh(sh_coeffs) => {
+                cloud.add_gaussian_with_sh(row.position, row.scale, row.rotation, sh_coeffs, row.opacity);
+            }
+            ColorOrSh::Color(color) => {
+                cloud.add_gaussian(row.position, row.scale, row.rotation, color, row.opacity);
+            }
         }
     }
 
     Ok(cloud)
 }
 
+/// Anchor a predicted position to its nearest back-projected depth point, so real measured
+/// geometry dominates over the learned estimate when available. A full snap rather than a blend:
+/// `generate_with_depth` already feeds the same depth data into the model's forward pass (see
+/// `preprocess_images_with_depth`'s depth channel), so this is a correction against whatever the
+/// (still RGB-dominated) prediction drifted to, not the only place depth reaches the pipeline.
+fn nearest_point(seed: &[[f32; 3]], predicted: [f32; 3]) -> [f32; 3] {
+    let mut best = seed[0];
+    let mut best_dist = f32::INFINITY;
+
+    for &candidate in seed {
+        let d = [
+            candidate[0] - predicted[0],
+            candidate[1] - predicted[1],
+            candidate[2] - predicted[2],
+        ];
+        let dist = d[0] * d[0] + d[1] * d[1] + d[2] * d[2];
+        if dist < best_dist {
+            best_dist = dist;
+            best = candidate;
+        }
+    }
+
+    best
+}
+
 #[cfg(test)]
 mod tests {
     use burn_ndarray::NdArray;
@@ -205,14 +541,44 @@ mod tests {
     }
 
     #[test]
-    fn test_camera_features() {
+    fn test_plucker_ray_direction_is_normalized() {
         let camera = CameraInfo {
             azimuth: 0.0,
             elevation: 0.0,
             radius: 2.0,
+            pose: None,
+            fx: 256.0,
+            fy: 256.0,
+            cx: 128.0,
+            cy: 128.0,
+            depth_scale: 1.0,
+        };
+        let (direction, _moment) = camera.plucker_ray(128, 128);
+        let len = (direction[0] * direction[0] + direction[1] * direction[1] + direction[2] * direction[2]).sqrt();
+        assert!((len - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_camera_info_from_explicit_pose() {
+        let pose = CameraPose::from_rotation_translation(
+            [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+            [0.0, 0.0, 3.0],
+        );
+        let camera = CameraInfo::from_pose(pose, 256.0, 256.0, 128.0, 128.0, 1.0);
+        let (direction, _moment) = camera.plucker_ray(128, 128);
+        assert!((direction[2] - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_unproject_depth_seeds_point_in_front_of_camera() {
+        let camera = &CameraInfo::default_4view()[0];
+        let depth = DepthMap {
+            samples: vec![1.0; 4],
+            width: 2,
+            height: 2,
         };
-        let features = camera.to_features();
-        assert_eq!(features.len(), 6);
+        let points = camera.unproject_depth(&depth);
+        assert_eq!(points.len(), 4);
     }
 
     #[test]
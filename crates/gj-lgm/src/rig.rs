@@ -0,0 +1,127 @@
+use crate::preprocessing::CameraInfo;
+
+/// Number of views the LGM model was trained on. Captures with a different view count are
+/// resampled to this before inference via [`resample_to`].
+pub const MODEL_VIEW_COUNT: usize = 4;
+
+/// A named multi-view capture layout. Each preset generates evenly-spaced orbit cameras at a
+/// fixed radius, so callers don't have to hand-roll [`CameraInfo`] arrays for common rigs.
+#[derive(Clone, Debug)]
+pub enum CameraRig {
+    /// The 4 cardinal views LGM was trained on (0/90/180/270 degrees azimuth, no elevation).
+    FourView,
+    /// 6 views around a cube: 4 cardinal azimuths plus top and bottom.
+    SixViewCube,
+    /// 8 views evenly spaced around a full ring.
+    EightViewRing,
+    /// `n` evenly-spaced views around a full orbit, for arbitrary capture rigs.
+    OrbitN(usize),
+}
+
+impl CameraRig {
+    /// Number of views this rig produces.
+    pub fn view_count(&self) -> usize {
+        match self {
+            CameraRig::FourView => 4,
+            CameraRig::SixViewCube => 6,
+            CameraRig::EightViewRing => 8,
+            CameraRig::OrbitN(n) => *n,
+        }
+    }
+
+    /// Generate the per-view camera poses for this rig, at the default orbit radius.
+    pub fn cameras(&self) -> Vec<CameraInfo> {
+        match self {
+            CameraRig::FourView => CameraInfo::default_4view().to_vec(),
+            CameraRig::SixViewCube => {
+                let mut cameras: Vec<CameraInfo> = orbit_cameras(4, 0.0);
+                cameras.push(orbit_camera(0.0, 90.0));
+                cameras.push(orbit_camera(0.0, -90.0));
+                cameras
+            }
+            CameraRig::EightViewRing => orbit_cameras(8, 0.0),
+            CameraRig::OrbitN(n) => orbit_cameras((*n).max(1), 0.0),
+        }
+    }
+}
+
+fn orbit_camera(azimuth: f32, elevation: f32) -> CameraInfo {
+    CameraInfo {
+        azimuth,
+        elevation,
+        radius: 2.0,
+        pose: None,
+        fx: 256.0,
+        fy: 256.0,
+        cx: 128.0,
+        cy: 128.0,
+        depth_scale: 1.0,
+    }
+}
+
+fn orbit_cameras(count: usize, elevation: f32) -> Vec<CameraInfo> {
+    (0..count)
+        .map(|i| orbit_camera(360.0 * (i as f32) / (count as f32), elevation))
+        .collect()
+}
+
+/// Resample a sequence of per-view items to exactly `target` entries: repeat the items
+/// cyclically from the first (tiling) when there are too few, or drop trailing items when there are too many. Used to
+/// reconcile a capture rig's view count with [`MODEL_VIEW_COUNT`] before running the model.
+pub fn resample_to<T: Clone>(items: &[T], target: usize) -> Vec<T> {
+    if items.is_empty() || target == 0 {
+        return Vec::new();
+    }
+
+    if items.len() == target {
+        return items.to_vec();
+    }
+
+    if items.len() > target {
+        return items[..target].to_vec();
+    }
+
+    let mut resampled = items.to_vec();
+    while resampled.len() < target {
+        resampled.push(items[resampled.len() % items.len()].clone());
+    }
+    resampled
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rig_view_counts() {
+        assert_eq!(CameraRig::FourView.view_count(), 4);
+        assert_eq!(CameraRig::SixViewCube.view_count(), 6);
+        assert_eq!(CameraRig::EightViewRing.view_count(), 8);
+        assert_eq!(CameraRig::OrbitN(12).view_count(), 12);
+    }
+
+    #[test]
+    fn test_rig_cameras_match_view_count() {
+        for rig in [CameraRig::FourView, CameraRig::SixViewCube, CameraRig::EightViewRing, CameraRig::OrbitN(3)] {
+            assert_eq!(rig.cameras().len(), rig.view_count());
+        }
+    }
+
+    #[test]
+    fn test_resample_pads_by_tiling() {
+        let items = vec![1, 2, 3];
+        assert_eq!(resample_to(&items, 4), vec![1, 2, 3, 1]);
+    }
+
+    #[test]
+    fn test_resample_drops_extras() {
+        let items = vec![1, 2, 3, 4, 5];
+        assert_eq!(resample_to(&items, 4), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_resample_exact_count_is_unchanged() {
+        let items = vec![1, 2, 3, 4];
+        assert_eq!(resample_to(&items, 4), items);
+    }
+}
@@ -1,6 +1,8 @@
 // crates/gj-lgm/src/text_to_image.rs
 
 use gj_core::error::{Error, Result};
+use gj_core::progress::ProgressCallback;
+use std::io::BufRead;
 use std::path::PathBuf;
 use serde::{Deserialize, Serialize};
 
@@ -35,6 +37,46 @@ struct GenerateResponse {
     error: Option<String>,
 }
 
+/// One incremental update from the `/generate_stream` endpoint: a line of JSON per optimization
+/// step, terminated by a line carrying `output_path` (success) or `error` (failure).
+#[derive(Deserialize)]
+struct StreamUpdate {
+    iteration: usize,
+    total: usize,
+    preview_path: Option<String>,
+    output_path: Option<String>,
+    error: Option<String>,
+}
+
+/// Convert a GaussianDreamer service output path to a host path relative to the project root.
+fn resolve_host_path(output_path: &str) -> Result<PathBuf> {
+    let host_path = if output_path.starts_with("/app/outputs/") {
+        // Docker: /app/outputs/file.ply -> outputs/file.ply
+        PathBuf::from(output_path.replace("/app/outputs/", "outputs/"))
+    } else if output_path.starts_with("../outputs/") {
+        // Local: ../outputs/file.ply -> outputs/file.ply
+        PathBuf::from(output_path.replace("../outputs/", "outputs/"))
+    } else if output_path.starts_with("outputs/") {
+        // Already correct
+        PathBuf::from(output_path)
+    } else {
+        // Unknown format - try to extract just the filename
+        let filename = std::path::Path::new(output_path)
+            .file_name()
+            .ok_or_else(|| Error::InvalidConfig("Invalid output path".to_string()))?;
+        PathBuf::from("outputs").join(filename)
+    };
+
+    if !host_path.exists() {
+        return Err(Error::InvalidConfig(
+            format!("Generated file not found at: {}. Original path was: {}",
+                    host_path.display(), output_path)
+        ));
+    }
+
+    Ok(host_path)
+}
+
 /// Generate Gaussian Splats directly from text prompt using GaussianDreamer service
 ///
 /// This communicates with a Python service running GaussianDreamer
@@ -75,34 +117,9 @@ pub fn generate_gaussians_from_prompt(
             let output_path = result.output_path
                 .ok_or_else(|| Error::InvalidConfig("No output path returned".to_string()))?;
 
-            // Convert service path to host path relative to project root
-            let host_path = if output_path.starts_with("/app/outputs/") {
-                // Docker: /app/outputs/file.ply -> outputs/file.ply
-                PathBuf::from(output_path.replace("/app/outputs/", "outputs/"))
-            } else if output_path.starts_with("../outputs/") {
-                // Local: ../outputs/file.ply -> outputs/file.ply
-                PathBuf::from(output_path.replace("../outputs/", "outputs/"))
-            } else if output_path.starts_with("outputs/") {
-                // Already correct
-                PathBuf::from(output_path.clone())
-            } else {
-                // Unknown format - try to extract just the filename
-                let filename = std::path::Path::new(&output_path)
-                    .file_name()
-                    .ok_or_else(|| Error::InvalidConfig("Invalid output path".to_string()))?;
-                PathBuf::from("outputs").join(filename)
-            };
-
+            let host_path = resolve_host_path(&output_path)?;
             println!("✓ GaussianDreamer generated: {}", host_path.display());
 
-            // Verify the file exists
-            if !host_path.exists() {
-                return Err(Error::InvalidConfig(
-                    format!("Generated file not found at: {}. Original path was: {}",
-                            host_path.display(), output_path)
-                ));
-            }
-
             Ok(host_path)
         }
         "error" => {
@@ -115,6 +132,83 @@ pub fn generate_gaussians_from_prompt(
     }
 }
 
+/// Generate Gaussian Splats from a text prompt, streaming live optimization progress from the
+/// service's `/generate_stream` endpoint into `callback` instead of blocking silently until the
+/// run finishes. The service is expected to respond with one JSON object per line (either plain
+/// newline-delimited or SSE-style `data: {...}` lines), each carrying the current
+/// `{iteration, total}` and an optional `preview_path`, with a final line carrying either
+/// `output_path` (success) or `error` (failure). If `callback.should_cancel()` returns true
+/// between updates, a best-effort `/cancel` request is sent and the connection is dropped.
+pub fn generate_gaussians_from_prompt_streaming(
+    prompt: &str,
+    config: &GaussianDreamerConfig,
+    callback: &mut dyn ProgressCallback,
+) -> Result<PathBuf> {
+    let client = reqwest::blocking::Client::new();
+    let url = format!("{}/generate_stream", config.service_url);
+
+    let request_body = GenerateRequest {
+        prompt: prompt.to_string(),
+        guidance_scale: config.guidance_scale,
+        num_iterations: config.num_iterations,
+    };
+
+    let response = client
+        .post(&url)
+        .json(&request_body)
+        .send()
+        .map_err(|e| Error::InvalidConfig(format!("Failed to connect to GaussianDreamer service: {}. Make sure the Python service is running.", e)))?;
+
+    if !response.status().is_success() {
+        return Err(Error::InvalidConfig(
+            format!("GaussianDreamer service returned error: {}", response.status())
+        ));
+    }
+
+    let reader = std::io::BufReader::new(response);
+
+    for line in reader.lines() {
+        if callback.should_cancel() {
+            let _ = client.post(format!("{}/cancel", config.service_url)).send();
+            return Err(Error::InvalidConfig("Generation cancelled".to_string()));
+        }
+
+        let line = line.map_err(|e| Error::InvalidConfig(format!("Stream read error: {}", e)))?;
+        let payload = line.trim().strip_prefix("data: ").unwrap_or(line.trim());
+        if payload.is_empty() {
+            continue;
+        }
+
+        let update: StreamUpdate = serde_json::from_str(payload)
+            .map_err(|e| Error::InvalidConfig(format!("Failed to parse stream update: {}", e)))?;
+
+        if let Some(error) = update.error {
+            return Err(Error::InvalidConfig(format!("GaussianDreamer error: {}", error)));
+        }
+
+        if let Some(output_path) = update.output_path {
+            let host_path = resolve_host_path(&output_path)?;
+            callback.update(1.0, "Generation complete");
+            return Ok(host_path);
+        }
+
+        let progress = if update.total > 0 {
+            update.iteration as f32 / update.total as f32
+        } else {
+            0.0
+        };
+        callback.update(
+            progress,
+            &match &update.preview_path {
+                Some(preview) => format!("iteration {}/{} (preview: {})", update.iteration, update.total, preview),
+                None => format!("iteration {}/{}", update.iteration, update.total),
+            },
+        );
+    }
+
+    Err(Error::InvalidConfig("Stream ended without a final output path".to_string()))
+}
+
 /// Check if GaussianDreamer service is running
 pub fn check_service_health(service_url: &str) -> Result<bool> {
     let client = reqwest::blocking::Client::new();
@@ -137,4 +231,25 @@ mod tests {
         let healthy = check_service_health(&config.service_url).unwrap();
         assert!(healthy, "GaussianDreamer service should be running");
     }
+
+    #[test]
+    #[ignore] // Only run when service is actually running
+    fn test_streaming_generation_reports_progress() {
+        struct RecordingCallback {
+            updates: Vec<f32>,
+        }
+
+        impl ProgressCallback for RecordingCallback {
+            fn update(&mut self, progress: f32, _message: &str) {
+                self.updates.push(progress);
+            }
+        }
+
+        let config = GaussianDreamerConfig::default();
+        let mut callback = RecordingCallback { updates: Vec::new() };
+        let result = generate_gaussians_from_prompt_streaming("a red sports car", &config, &mut callback);
+
+        assert!(result.is_ok());
+        assert!(!callback.updates.is_empty());
+    }
 }
\ No newline at end of file
@@ -0,0 +1,137 @@
+//! Keyframed camera trajectories for scripted fly-throughs and turntables, as opposed to
+//! [`Camera`]'s interactive orbit/zoom/pan. A [`CameraPath`] holds timestamped keyframes and
+//! produces a smoothly interpolated [`Camera`] at any point along the path.
+
+use glam::Vec3;
+
+use crate::camera::Camera;
+
+/// One keyframe of a [`CameraPath`]: everything `Camera` derives its `position` from, plus a
+/// timestamp. Orientation is kept in spherical `azimuth`/`elevation` form (not `position`
+/// directly) so interpolation can shortest-arc-wrap azimuth and clamp elevation the same way
+/// [`Camera::rotate`] does.
+#[derive(Clone, Debug)]
+pub struct CameraKeyframe {
+    pub time: f32,
+    pub target: Vec3,
+    pub distance: f32,
+    pub azimuth: f32,
+    pub elevation: f32,
+    pub fov: f32,
+}
+
+/// An ordered sequence of [`CameraKeyframe`]s, sampled with a Catmull-Rom spline through
+/// `target`/`distance`/`fov` and shortest-arc-wrapped `azimuth`.
+#[derive(Clone, Debug, Default)]
+pub struct CameraPath {
+    keyframes: Vec<CameraKeyframe>,
+}
+
+/// Catmull-Rom interpolation through `p1`..`p2` (with neighbors `p0`/`p3`) at `t` in `[0, 1]`.
+fn catmull_rom(p0: f32, p1: f32, p2: f32, p3: f32, t: f32) -> f32 {
+    0.5 * ((2.0 * p1)
+        + (-p0 + p2) * t
+        + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * t * t
+        + (-p0 + 3.0 * p1 - 3.0 * p2 + p3) * t * t * t)
+}
+
+fn catmull_rom_vec3(p0: Vec3, p1: Vec3, p2: Vec3, p3: Vec3, t: f32) -> Vec3 {
+    Vec3::new(
+        catmull_rom(p0.x, p1.x, p2.x, p3.x, t),
+        catmull_rom(p0.y, p1.y, p2.y, p3.y, t),
+        catmull_rom(p0.z, p1.z, p2.z, p3.z, t),
+    )
+}
+
+/// Shift `angles[1..]` by multiples of 360 degrees so each step from its predecessor is the
+/// shortest arc, e.g. `[350, 10]` becomes `[350, 370]` rather than spinning the long way around.
+fn unwrap_angles(mut angles: [f32; 4]) -> [f32; 4] {
+    for i in 1..angles.len() {
+        while angles[i] - angles[i - 1] > 180.0 {
+            angles[i] -= 360.0;
+        }
+        while angles[i] - angles[i - 1] < -180.0 {
+            angles[i] += 360.0;
+        }
+    }
+    angles
+}
+
+impl CameraPath {
+    /// Build a path from `keyframes`, sorted by `time`.
+    pub fn new(mut keyframes: Vec<CameraKeyframe>) -> Self {
+        keyframes.sort_by(|a, b| a.time.partial_cmp(&b.time).unwrap());
+        Self { keyframes }
+    }
+
+    /// A full 360-degree azimuth sweep around `target` at a fixed `distance`/`elevation`,
+    /// evenly spaced over `frames + 1` keyframes spanning `t = 0..1` (the last one, at 360
+    /// degrees, closes the loop back onto the first).
+    pub fn turntable(target: Vec3, distance: f32, elevation: f32, frames: usize) -> Self {
+        let frames = frames.max(1);
+        let keyframes = (0..=frames)
+            .map(|i| CameraKeyframe {
+                time: i as f32 / frames as f32,
+                target,
+                distance,
+                azimuth: 360.0 * i as f32 / frames as f32,
+                elevation,
+                fov: 50.0,
+            })
+            .collect();
+        Self::new(keyframes)
+    }
+
+    /// Sample the path at `t`, clamped to the keyframe time range. `template` supplies the
+    /// fields keyframes don't carry (`up`/`aspect_ratio`/`near`/`far`); `position` is re-derived
+    /// via [`Camera::update_position`] after interpolation.
+    pub fn sample(&self, t: f32, template: &Camera) -> Camera {
+        let mut camera = template.clone();
+
+        let Some(first) = self.keyframes.first() else { return camera };
+        if self.keyframes.len() == 1 {
+            camera.target = first.target;
+            camera.distance = first.distance;
+            camera.azimuth = first.azimuth;
+            camera.elevation = first.elevation.clamp(-89.0, 89.0);
+            camera.fov = first.fov;
+            camera.update_position();
+            return camera;
+        }
+
+        let last = self.keyframes.last().unwrap();
+        let t = t.clamp(first.time, last.time);
+
+        let segment = self
+            .keyframes
+            .windows(2)
+            .position(|w| t <= w[1].time)
+            .unwrap_or(self.keyframes.len() - 2);
+
+        let i1 = segment;
+        let i2 = segment + 1;
+        let i0 = i1.saturating_sub(1);
+        let i3 = (i2 + 1).min(self.keyframes.len() - 1);
+
+        let (k0, k1, k2, k3) = (
+            &self.keyframes[i0],
+            &self.keyframes[i1],
+            &self.keyframes[i2],
+            &self.keyframes[i3],
+        );
+
+        let span = k2.time - k1.time;
+        let local_t = if span > 0.0 { (t - k1.time) / span } else { 0.0 };
+
+        let azimuths = unwrap_angles([k0.azimuth, k1.azimuth, k2.azimuth, k3.azimuth]);
+
+        camera.target = catmull_rom_vec3(k0.target, k1.target, k2.target, k3.target, local_t);
+        camera.distance = catmull_rom(k0.distance, k1.distance, k2.distance, k3.distance, local_t);
+        camera.azimuth = catmull_rom(azimuths[0], azimuths[1], azimuths[2], azimuths[3], local_t).rem_euclid(360.0);
+        camera.elevation = catmull_rom(k0.elevation, k1.elevation, k2.elevation, k3.elevation, local_t).clamp(-89.0, 89.0);
+        camera.fov = catmull_rom(k0.fov, k1.fov, k2.fov, k3.fov, local_t);
+        camera.update_position();
+
+        camera
+    }
+}
@@ -1,6 +1,7 @@
 use wgpu::util::DeviceExt;
 use gj_core::gaussian_cloud::GaussianCloud;
 use crate::camera::Camera;
+use crate::sort::{self, SortedOrder};
 
 // Quad vertices for instanced rendering (4 corners of a billboard)
 const QUAD_VERTICES: &[[f32; 2]] = &[
@@ -12,6 +13,43 @@ const QUAD_VERTICES: &[[f32; 2]] = &[
 
 const QUAD_INDICES: &[u16] = &[0, 1, 2, 2, 1, 3];
 
+/// Highest SH band the GPU path evaluates (matches `gj_core::gaussian_cloud::sh_basis`'s max).
+const SH_DEGREE: usize = 3;
+/// `(SH_DEGREE + 1)^2` terms, each an (r, g, b) triple -> floats packed per instance.
+const SH_TERMS: usize = (SH_DEGREE + 1) * (SH_DEGREE + 1);
+const SH_FLOATS_PER_INSTANCE: usize = SH_TERMS * 3;
+
+/// Format of the intermediate splat target. Gaussian alpha accumulation can exceed 1.0 in
+/// overlapping regions, so the splat pass renders HDR and a separate resolve pass tone-maps down
+/// to the swapchain format, instead of clipping LDR color directly.
+const HDR_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba16Float;
+
+/// Selects which operator `tonemap.wgsl`'s resolve pass applies. Mirrors the `mode` field of
+/// [`TonemapUniforms`]; keep `as_u32` in sync with `fs_main` there, which applies Reinhard for
+/// mode 0 and ACES filmic for any other value.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TonemapMode {
+    Reinhard,
+    AcesFilmic,
+}
+
+impl TonemapMode {
+    fn as_u32(self) -> u32 {
+        match self {
+            TonemapMode::Reinhard => 0,
+            TonemapMode::AcesFilmic => 1,
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct TonemapUniforms {
+    exposure: f32,
+    mode: u32,
+    _padding: [f32; 2],
+}
+
 #[repr(C)]
 #[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
 struct GaussianInstance {
@@ -33,6 +71,77 @@ struct Uniforms {
     _padding1: f32,
     viewport: [f32; 2],
     focal: [f32; 2],
+    // Whether `sh_buffer` holds real coefficients (1) or the cloud had none, so `gaussian.wgsl`'s
+    // fragment stage should fall back to the flat per-instance `color` attribute (0).
+    has_sh: u32,
+    _padding2: [f32; 3],
+}
+
+/// Mirrors wgpu's `DrawIndexedIndirectArgs` byte layout exactly, so `indirect_buffer` can be
+/// bound both as the draw call's indirect source and as a compute-writable storage buffer that
+/// `cull.wgsl` increments in place (see `cull_and_draw`).
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct IndirectArgs {
+    index_count: u32,
+    instance_count: u32,
+    first_index: u32,
+    base_vertex: i32,
+    first_instance: u32,
+}
+
+/// Evaluate each Gaussian's view-dependent color from its `sh_coefficients` against `camera`,
+/// falling back to the flat `colors` entry for Gaussians with no SH data. The view direction for
+/// Gaussian `i` is `normalize(cloud.positions[i] - camera.position)` - the direction *from* the
+/// camera *to* the Gaussian, matching reference 3DGS (`dir = normalize(pos - campos)`) and
+/// `gaussian.wgsl`'s `normalize(world_position - camera_pos)`. `sh_basis` applies the standard
+/// polynomials directly, so negating this direction flips the sign of every odd band.
+pub fn evaluate_colors(cloud: &GaussianCloud, camera: &Camera) -> Vec<[f32; 3]> {
+    (0..cloud.count)
+        .map(|i| {
+            let pos = cloud.positions[i];
+            let from_camera = [
+                pos[0] - camera.position.x,
+                pos[1] - camera.position.y,
+                pos[2] - camera.position.z,
+            ];
+            let len = (from_camera[0] * from_camera[0] + from_camera[1] * from_camera[1] + from_camera[2] * from_camera[2]).sqrt();
+            let view_dir = if len > 0.0 {
+                [from_camera[0] / len, from_camera[1] / len, from_camera[2] / len]
+            } else {
+                [0.0, 0.0, 1.0]
+            };
+
+            cloud.evaluate_color(i, view_dir)
+        })
+        .collect()
+}
+
+/// Row `i` (0-3) of `m`, read out of its column-major storage.
+fn matrix_row(m: &glam::Mat4, i: usize) -> glam::Vec4 {
+    glam::Vec4::new(m.x_axis[i], m.y_axis[i], m.z_axis[i], m.w_axis[i])
+}
+
+/// Indices of Gaussians whose center lies inside `camera`'s view frustum, via the standard
+/// Gribb-Hartmann plane extraction from `camera.view_projection_matrix()`. Used to drop
+/// off-screen splats before sort/render; a center-only test, so splats whose footprint merely
+/// overlaps the frustum edge may still be culled.
+pub fn select_in_frustum(cloud: &GaussianCloud, camera: &Camera) -> Vec<u32> {
+    let vp = camera.view_projection_matrix();
+    let (r0, r1, r2, r3) = (matrix_row(&vp, 0), matrix_row(&vp, 1), matrix_row(&vp, 2), matrix_row(&vp, 3));
+
+    // `Camera::projection_matrix` uses glam's `perspective_rh` (zero-to-one clip-space z, as
+    // wgpu/Vulkan/D3D expect), so the near plane is just `z >= 0` (row `r2` alone) rather than
+    // OpenGL's `z >= -w`.
+    let planes = [r3 + r0, r3 - r0, r3 + r1, r3 - r1, r2, r3 - r2];
+
+    (0..cloud.count as u32)
+        .filter(|&i| {
+            let p = cloud.positions[i as usize];
+            let point = glam::Vec4::new(p[0], p[1], p[2], 1.0);
+            planes.iter().all(|plane| plane.dot(point) >= 0.0)
+        })
+        .collect()
 }
 
 pub struct GaussianRenderer {
@@ -43,6 +152,37 @@ pub struct GaussianRenderer {
     quad_vertex_buffer: wgpu::Buffer,
     quad_index_buffer: wgpu::Buffer,
     instance_buffer: Option<wgpu::Buffer>,
+    // Scratch buffer the gather pass writes sorted instances into before `cull` reads them. Same
+    // contents as `instance_buffer`, just reordered back-to-front for the current view.
+    sorted_instance_buffer: Option<wgpu::Buffer>,
+    // CPU copy of the loaded instances and their positions; `positions` feeds the per-frame CPU
+    // sort keys. The gather pass reads `instance_buffer`, not this copy.
+    instances: Vec<GaussianInstance>,
+    positions: Vec<[f32; 3]>,
+
+    gather_pipeline: wgpu::ComputePipeline,
+    gather_bind_group_layout: wgpu::BindGroupLayout,
+
+    // Frustum + screen-space size culling, run after sort_and_gather against
+    // `sorted_instance_buffer`. `visible_instance_buffer` holds the compacted survivors (sized
+    // for the worst case of `num_gaussians`); `indirect_buffer` is both the draw call's indirect
+    // args source and the buffer the compute pass atomically increments `instance_count` in.
+    cull_pipeline: wgpu::ComputePipeline,
+    cull_bind_group_layout: wgpu::BindGroupLayout,
+    visible_instance_buffer: Option<wgpu::Buffer>,
+    indirect_buffer: Option<wgpu::Buffer>,
+    /// Minimum projected splat radius, in pixels, to survive culling. Exposed to `draw_ui`'s
+    /// side panel.
+    pub min_pixel_radius: f32,
+
+    // Packed per-instance SH coefficients (degree-3, zero-padded/truncated from whatever degree
+    // the cloud actually carries), indexed by instance index. Bound as `gaussian.wgsl`'s
+    // `@group(1)` so the vertex/fragment stage can evaluate view-dependent color; all-zero and
+    // `Uniforms::has_sh == 0` when the loaded cloud has no SH data at all.
+    sh_buffer: Option<wgpu::Buffer>,
+    sh_bind_group_layout: wgpu::BindGroupLayout,
+    sh_bind_group: Option<wgpu::BindGroup>,
+    has_sh: bool,
 
     uniform_buffer: wgpu::Buffer,
     bind_group: wgpu::BindGroup,
@@ -51,6 +191,24 @@ pub struct GaussianRenderer {
 
     // Cache last camera state to avoid redundant updates
     last_view_proj: Option<[[f32; 4]; 4]>,
+
+    // HDR splat target + tone-mapping resolve pass. The splat pipeline always renders into
+    // `hdr_view` (recreated by `ensure_hdr_target` when the viewport size changes); the resolve
+    // pass then samples it down into whatever format the caller's `render` target actually is.
+    swapchain_format: wgpu::TextureFormat,
+    hdr_texture: Option<wgpu::Texture>,
+    hdr_view: Option<wgpu::TextureView>,
+    hdr_size: (u32, u32),
+    hdr_sampler: wgpu::Sampler,
+    tonemap_pipeline: wgpu::RenderPipeline,
+    tonemap_bind_group_layout: wgpu::BindGroupLayout,
+    tonemap_uniform_buffer: wgpu::Buffer,
+    tonemap_bind_group: Option<wgpu::BindGroup>,
+
+    /// Linear exposure multiplier applied before tone-mapping. Exposed to `draw_ui`'s side panel.
+    pub exposure: f32,
+    /// Tone-mapping operator applied by the resolve pass. Exposed to `draw_ui`'s side panel.
+    pub tonemap_mode: TonemapMode,
 }
 
 impl GaussianRenderer {
@@ -59,7 +217,13 @@ impl GaussianRenderer {
         queue: wgpu::Queue,
         format: wgpu::TextureFormat,
     ) -> Self {
-        // Use the simplified, faster shader
+        // `gaussian.wgsl` binds the SH coefficient buffer at
+        // `@group(1) @binding(0) var<storage, read> sh_coefficients: array<f32>;` (see
+        // `sh_bind_group_layout` below): `SH_FLOATS_PER_INSTANCE` floats per instance,
+        // band-ordered the same way as `gj_core::gaussian_cloud::sh_basis`. Its fragment stage
+        // evaluates that basis against `normalize(world_position - uniforms.camera_pos)` and
+        // blends bands in when `uniforms.has_sh != 0`, falling back to the flat per-instance
+        // `color` attribute otherwise (degree-0, or no SH data loaded at all).
         let shader_source = include_str!("../shaders/gaussian.wgsl");
         let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
             label: Some("Gaussian Shader"),
@@ -109,9 +273,23 @@ impl GaussianRenderer {
             }],
         });
 
+        let sh_bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("SH Bind Group Layout"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Storage { read_only: true },
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            }],
+        });
+
         let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
             label: Some("Pipeline Layout"),
-            bind_group_layouts: &[&bind_group_layout],
+            bind_group_layouts: &[&bind_group_layout, &sh_bind_group_layout],
             push_constant_ranges: &[],
         });
 
@@ -182,7 +360,7 @@ impl GaussianRenderer {
                 entry_point: Some("fs_main"),
                 compilation_options: Default::default(),
                 targets: &[Some(wgpu::ColorTargetState {
-                    format,
+                    format: HDR_FORMAT,
                     blend: Some(wgpu::BlendState {
                         color: wgpu::BlendComponent {
                             src_factor: wgpu::BlendFactor::One,
@@ -216,6 +394,214 @@ impl GaussianRenderer {
             cache: None,
         });
 
+        let gather_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Instance Gather Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("../shaders/instance_gather.wgsl").into()),
+        });
+
+        let gather_bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Instance Gather Bind Group Layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        });
+
+        let gather_pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Instance Gather Pipeline Layout"),
+            bind_group_layouts: &[&gather_bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        let gather_pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("Instance Gather Pipeline"),
+            layout: Some(&gather_pipeline_layout),
+            module: &gather_shader,
+            entry_point: Some("gather"),
+            compilation_options: Default::default(),
+            cache: None,
+        });
+
+        let cull_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Cull Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("../shaders/cull.wgsl").into()),
+        });
+
+        let cull_bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Cull Bind Group Layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 3,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        });
+
+        let cull_pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Cull Pipeline Layout"),
+            bind_group_layouts: &[&cull_bind_group_layout],
+            push_constant_ranges: &[wgpu::PushConstantRange {
+                stages: wgpu::ShaderStages::COMPUTE,
+                range: 0..4,
+            }],
+        });
+
+        let cull_pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("Cull Pipeline"),
+            layout: Some(&cull_pipeline_layout),
+            module: &cull_shader,
+            entry_point: Some("cull"),
+            compilation_options: Default::default(),
+            cache: None,
+        });
+
+        let hdr_sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("HDR Resolve Sampler"),
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            ..Default::default()
+        });
+
+        let tonemap_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Tonemap Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("../shaders/tonemap.wgsl").into()),
+        });
+
+        let tonemap_uniform_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Tonemap Uniform Buffer"),
+            size: std::mem::size_of::<TonemapUniforms>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let tonemap_bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Tonemap Bind Group Layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        });
+
+        let tonemap_pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Tonemap Pipeline Layout"),
+            bind_group_layouts: &[&tonemap_bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        let tonemap_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Tonemap Pipeline"),
+            layout: Some(&tonemap_pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &tonemap_shader,
+                entry_point: Some("vs_main"),
+                compilation_options: Default::default(),
+                buffers: &[],
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &tonemap_shader,
+                entry_point: Some("fs_main"),
+                compilation_options: Default::default(),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format,
+                    blend: None,
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+            }),
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+            cache: None,
+        });
+
         Self {
             device,
             queue,
@@ -223,15 +609,76 @@ impl GaussianRenderer {
             quad_vertex_buffer,
             quad_index_buffer,
             instance_buffer: None,
+            sorted_instance_buffer: None,
+            instances: Vec::new(),
+            positions: Vec::new(),
+            gather_pipeline,
+            gather_bind_group_layout,
+            cull_pipeline,
+            cull_bind_group_layout,
+            visible_instance_buffer: None,
+            indirect_buffer: None,
+            min_pixel_radius: 1.0,
+            sh_buffer: None,
+            sh_bind_group_layout,
+            sh_bind_group: None,
+            has_sh: false,
             uniform_buffer,
             bind_group,
             num_gaussians: 0,
             last_view_proj: None,
+            swapchain_format: format,
+            hdr_texture: None,
+            hdr_view: None,
+            hdr_size: (0, 0),
+            hdr_sampler,
+            tonemap_pipeline,
+            tonemap_bind_group_layout,
+            tonemap_uniform_buffer,
+            tonemap_bind_group: None,
+            exposure: 1.0,
+            tonemap_mode: TonemapMode::AcesFilmic,
+        }
+    }
+
+    /// (Re)create the HDR splat target and its resolve bind group when the viewport size
+    /// changes (or on first use). The swapchain format never factors in here — the HDR target is
+    /// always [`HDR_FORMAT`]; only the final resolve pass targets the caller's format.
+    fn ensure_hdr_target(&mut self, width: u32, height: u32) {
+        if self.hdr_view.is_some() && self.hdr_size == (width, height) {
+            return;
         }
+
+        let texture = self.device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("HDR Splat Target"),
+            size: wgpu::Extent3d { width, height, depth_or_array_layers: 1 },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: HDR_FORMAT,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Tonemap Bind Group"),
+            layout: &self.tonemap_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry { binding: 0, resource: wgpu::BindingResource::TextureView(&view) },
+                wgpu::BindGroupEntry { binding: 1, resource: wgpu::BindingResource::Sampler(&self.hdr_sampler) },
+                wgpu::BindGroupEntry { binding: 2, resource: self.tonemap_uniform_buffer.as_entire_binding() },
+            ],
+        });
+
+        self.hdr_texture = Some(texture);
+        self.hdr_view = Some(view);
+        self.hdr_size = (width, height);
+        self.tonemap_bind_group = Some(bind_group);
     }
 
     pub fn load_gaussians(&mut self, cloud: &GaussianCloud) {
-        let instances: Vec<GaussianInstance> = (0..cloud.count)
+        let kept_indices: Vec<usize> = (0..cloud.count)
             .filter(|&i| {
                 let opacity = cloud.opacity[i];
                 let scale_avg = (cloud.scales[i][0] + cloud.scales[i][1] + cloud.scales[i][2]) / 3.0;
@@ -244,7 +691,11 @@ impl GaussianRenderer {
                     cloud.positions[i][1].is_finite() &&
                     cloud.positions[i][2].is_finite()
             })
-            .map(|i| GaussianInstance {
+            .collect();
+
+        let instances: Vec<GaussianInstance> = kept_indices
+            .iter()
+            .map(|&i| GaussianInstance {
                 position: cloud.positions[i],
                 _padding1: 0.0,
                 color: cloud.colors[i],  // USE ACTUAL COLORS
@@ -255,20 +706,167 @@ impl GaussianRenderer {
             })
             .collect();
 
+        // Pack each kept Gaussian's SH coefficients (if any) into a fixed-size, degree-3 slot so
+        // the GPU side never has to branch on how many bands a given row actually has; missing
+        // higher bands are left zero, which contributes nothing to the basis evaluation anyway.
+        self.has_sh = cloud.sh_coefficients.is_some();
+        let sh_packed: Vec<f32> = kept_indices
+            .iter()
+            .flat_map(|&i| {
+                let mut slot = [0.0f32; SH_FLOATS_PER_INSTANCE];
+                if let Some(row) = cloud.sh_coefficients.as_ref().and_then(|sh| sh.get(i)) {
+                    let n = row.len().min(SH_FLOATS_PER_INSTANCE);
+                    slot[..n].copy_from_slice(&row[..n]);
+                }
+                slot
+            })
+            .collect();
+
         self.instance_buffer = Some(
             self.device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
                 label: Some("Instance Buffer"),
                 contents: bytemuck::cast_slice(&instances),
-                usage: wgpu::BufferUsages::VERTEX,
+                // STORAGE so the gather compute pass can read it as the sort source; VERTEX so it
+                // can still be drawn directly while `sorted_instance_buffer` is being rebuilt.
+                usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::STORAGE,
+            })
+        );
+
+        self.sorted_instance_buffer = Some(
+            self.device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("Sorted Instance Buffer"),
+                size: (instances.len() * std::mem::size_of::<GaussianInstance>()) as u64,
+                usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::STORAGE,
+                mapped_at_creation: false,
+            })
+        );
+
+        self.visible_instance_buffer = Some(
+            self.device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("Visible Instance Buffer"),
+                size: (instances.len() * std::mem::size_of::<GaussianInstance>()) as u64,
+                usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::STORAGE,
+                mapped_at_creation: false,
+            })
+        );
+
+        let sh_buffer = self.device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("SH Coefficients Buffer"),
+            contents: bytemuck::cast_slice(&sh_packed),
+            usage: wgpu::BufferUsages::STORAGE,
+        });
+        self.sh_bind_group = Some(self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("SH Bind Group"),
+            layout: &self.sh_bind_group_layout,
+            entries: &[wgpu::BindGroupEntry { binding: 0, resource: sh_buffer.as_entire_binding() }],
+        }));
+        self.sh_buffer = Some(sh_buffer);
+
+        self.indirect_buffer = Some(
+            self.device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("Indirect Draw Args Buffer"),
+                contents: bytemuck::cast_slice(&[IndirectArgs {
+                    index_count: QUAD_INDICES.len() as u32,
+                    instance_count: 0,
+                    first_index: 0,
+                    base_vertex: 0,
+                    first_instance: 0,
+                }]),
+                usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::INDIRECT | wgpu::BufferUsages::COPY_DST,
             })
         );
 
+        self.positions = instances.iter().map(|i| i.position).collect();
         self.num_gaussians = instances.len() as u32;
+        self.instances = instances;
         self.last_view_proj = None;
 
         println!("Rendered {} / {} gaussians ({:.1}% kept)",
-                 instances.len(), cloud.count,
-                 100.0 * instances.len() as f32 / cloud.count.max(1) as f32);
+                 self.instances.len(), cloud.count,
+                 100.0 * self.instances.len() as f32 / cloud.count.max(1) as f32);
+    }
+
+    /// Sort the loaded instances back-to-front for `camera` and gather them (via a compute pass
+    /// recorded into `encoder`) into `sorted_instance_buffer`, so alpha blending composites in
+    /// the right order. No-op when nothing is loaded.
+    fn sort_and_gather(&mut self, encoder: &mut wgpu::CommandEncoder, camera: &Camera) {
+        let (Some(instance_buffer), Some(sorted_buffer)) =
+            (&self.instance_buffer, &self.sorted_instance_buffer)
+        else {
+            return;
+        };
+
+        let order_buffer = match sort::sort_indices(&self.device, &self.queue, encoder, &self.positions, instance_buffer, camera) {
+            SortedOrder::Cpu(indices) => self.device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("CPU Sort Order Buffer"),
+                contents: bytemuck::cast_slice(&indices),
+                usage: wgpu::BufferUsages::STORAGE,
+            }),
+            SortedOrder::Gpu(buffer) => buffer,
+        };
+
+        let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Instance Gather Bind Group"),
+            layout: &self.gather_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry { binding: 0, resource: instance_buffer.as_entire_binding() },
+                wgpu::BindGroupEntry { binding: 1, resource: sorted_buffer.as_entire_binding() },
+                wgpu::BindGroupEntry { binding: 2, resource: order_buffer.as_entire_binding() },
+            ],
+        });
+
+        let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+            label: Some("Instance Gather Pass"),
+            timestamp_writes: None,
+        });
+        pass.set_pipeline(&self.gather_pipeline);
+        pass.set_bind_group(0, &bind_group, &[]);
+        pass.dispatch_workgroups(self.num_gaussians.div_ceil(256).max(1), 1, 1);
+    }
+
+    /// Cull `sorted_instance_buffer` against `camera`'s frustum and `min_pixel_radius`, compacting
+    /// survivors into `visible_instance_buffer` and writing their count into `indirect_buffer` for
+    /// `draw_indexed_indirect`. No-op when nothing is loaded.
+    fn cull(&mut self, encoder: &mut wgpu::CommandEncoder) {
+        let (Some(sorted_buffer), Some(visible_buffer), Some(indirect_buffer)) =
+            (&self.sorted_instance_buffer, &self.visible_instance_buffer, &self.indirect_buffer)
+        else {
+            return;
+        };
+
+        // Reset `instance_count` (and re-assert the other fields, which never change) before the
+        // compute pass hands out fresh slots via atomicAdd.
+        self.queue.write_buffer(
+            indirect_buffer,
+            0,
+            bytemuck::cast_slice(&[IndirectArgs {
+                index_count: QUAD_INDICES.len() as u32,
+                instance_count: 0,
+                first_index: 0,
+                base_vertex: 0,
+                first_instance: 0,
+            }]),
+        );
+
+        let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Cull Bind Group"),
+            layout: &self.cull_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry { binding: 0, resource: self.uniform_buffer.as_entire_binding() },
+                wgpu::BindGroupEntry { binding: 1, resource: sorted_buffer.as_entire_binding() },
+                wgpu::BindGroupEntry { binding: 2, resource: visible_buffer.as_entire_binding() },
+                wgpu::BindGroupEntry { binding: 3, resource: indirect_buffer.as_entire_binding() },
+            ],
+        });
+
+        let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+            label: Some("Cull Pass"),
+            timestamp_writes: None,
+        });
+        pass.set_pipeline(&self.cull_pipeline);
+        pass.set_bind_group(0, &bind_group, &[]);
+        pass.set_push_constants(0, bytemuck::cast_slice(&[self.min_pixel_radius]));
+        pass.dispatch_workgroups(self.num_gaussians.div_ceil(256).max(1), 1, 1);
     }
 
     pub fn render(
@@ -302,50 +900,179 @@ impl GaussianRenderer {
                 _padding1: 0.0,
                 viewport: [viewport_size.0 as f32, viewport_size.1 as f32],
                 focal: [focal_x, focal_y],
+                has_sh: self.has_sh as u32,
+                _padding2: [0.0; 3],
             };
 
             self.queue.write_buffer(&self.uniform_buffer, 0, bytemuck::cast_slice(&[uniforms]));
             self.last_view_proj = Some(view_proj);
         }
 
-        let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-            label: Some("Gaussian Render Pass"),
+        self.sort_and_gather(encoder, camera);
+        self.cull(encoder);
+
+        self.ensure_hdr_target(viewport_size.0, viewport_size.1);
+        let hdr_view = self.hdr_view.as_ref().expect("ensure_hdr_target always sets hdr_view");
+
+        {
+            let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("Gaussian Render Pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: hdr_view,
+                    depth_slice: None,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color {
+                            r: 0.1,
+                            g: 0.1,
+                            b: 0.1,
+                            a: 1.0,
+                        }),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                    view: depth_view,
+                    depth_ops: Some(wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(1.0),
+                        store: wgpu::StoreOp::Store,
+                    }),
+                    stencil_ops: None,
+                }),
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+
+            render_pass.set_pipeline(&self.pipeline);
+            render_pass.set_bind_group(0, &self.bind_group, &[]);
+            if let Some(ref sh_bind_group) = self.sh_bind_group {
+                render_pass.set_bind_group(1, sh_bind_group, &[]);
+            }
+            render_pass.set_vertex_buffer(0, self.quad_vertex_buffer.slice(..));
+
+            if let (Some(ref visible_buffer), Some(ref indirect_buffer)) =
+                (&self.visible_instance_buffer, &self.indirect_buffer)
+            {
+                render_pass.set_vertex_buffer(1, visible_buffer.slice(..));
+                render_pass.set_index_buffer(self.quad_index_buffer.slice(..), wgpu::IndexFormat::Uint16);
+
+                // Instance count comes from `cull`'s atomic counter, written directly into
+                // `indirect_buffer`, so the CPU never learns (or needs) how many splats survived.
+                render_pass.draw_indexed_indirect(indirect_buffer, 0);
+            }
+        }
+
+        let tonemap_uniforms = TonemapUniforms {
+            exposure: self.exposure,
+            mode: self.tonemap_mode.as_u32(),
+            _padding: [0.0; 2],
+        };
+        self.queue.write_buffer(&self.tonemap_uniform_buffer, 0, bytemuck::cast_slice(&[tonemap_uniforms]));
+
+        let tonemap_bind_group =
+            self.tonemap_bind_group.as_ref().expect("ensure_hdr_target always sets tonemap_bind_group");
+
+        let mut resolve_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("Tonemap Resolve Pass"),
             color_attachments: &[Some(wgpu::RenderPassColorAttachment {
                 view,
                 depth_slice: None,
                 resolve_target: None,
-                ops: wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(wgpu::Color {
-                        r: 0.1,
-                        g: 0.1,
-                        b: 0.1,
-                        a: 1.0,
-                    }),
-                    store: wgpu::StoreOp::Store,
-                },
+                ops: wgpu::Operations { load: wgpu::LoadOp::Clear(wgpu::Color::BLACK), store: wgpu::StoreOp::Store },
             })],
-            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
-                view: depth_view,
-                depth_ops: Some(wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(1.0),
-                    store: wgpu::StoreOp::Store,
-                }),
-                stencil_ops: None,
-            }),
+            depth_stencil_attachment: None,
             timestamp_writes: None,
             occlusion_query_set: None,
         });
+        resolve_pass.set_pipeline(&self.tonemap_pipeline);
+        resolve_pass.set_bind_group(0, tonemap_bind_group, &[]);
+        resolve_pass.draw(0..3, 0..1);
+    }
+
+    /// Render the currently loaded cloud (via [`Self::load_gaussians`]) to an offscreen RGBA8
+    /// texture from `camera`'s point of view, and read it back into a CPU-side image.
+    ///
+    /// Used for turntable/thumbnail export, where there is no swapchain to draw into.
+    pub async fn render_to_rgba(&mut self, camera: &Camera, width: u32, height: u32) -> image::RgbaImage {
+        let color_texture = self.device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Offscreen Color Texture"),
+            size: wgpu::Extent3d { width, height, depth_or_array_layers: 1 },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba8UnormSrgb,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+        let color_view = color_texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let depth_texture = self.device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Offscreen Depth Texture"),
+            size: wgpu::Extent3d { width, height, depth_or_array_layers: 1 },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Depth32Float,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            view_formats: &[],
+        });
+        let depth_view = depth_texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let mut encoder = self.device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Offscreen Render Encoder"),
+        });
 
-        render_pass.set_pipeline(&self.pipeline);
-        render_pass.set_bind_group(0, &self.bind_group, &[]);
-        render_pass.set_vertex_buffer(0, self.quad_vertex_buffer.slice(..));
+        self.render(&mut encoder, &color_view, &depth_view, camera, (width, height));
 
-        if let Some(ref instance_buffer) = self.instance_buffer {
-            render_pass.set_vertex_buffer(1, instance_buffer.slice(..));
-            render_pass.set_index_buffer(self.quad_index_buffer.slice(..), wgpu::IndexFormat::Uint16);
+        // wgpu requires bytes-per-row to be a multiple of 256 for texture-to-buffer copies.
+        let unpadded_bytes_per_row = width * 4;
+        let align = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+        let padded_bytes_per_row = unpadded_bytes_per_row.div_ceil(align) * align;
 
-            // Draw instanced quads - 6 indices per quad, num_gaussians instances
-            render_pass.draw_indexed(0..6, 0, 0..self.num_gaussians);
+        let readback_buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Offscreen Readback Buffer"),
+            size: (padded_bytes_per_row * height) as u64,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        encoder.copy_texture_to_buffer(
+            wgpu::TexelCopyTextureInfo {
+                texture: &color_texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            wgpu::TexelCopyBufferInfo {
+                buffer: &readback_buffer,
+                layout: wgpu::TexelCopyBufferLayout {
+                    offset: 0,
+                    bytes_per_row: Some(padded_bytes_per_row),
+                    rows_per_image: Some(height),
+                },
+            },
+            wgpu::Extent3d { width, height, depth_or_array_layers: 1 },
+        );
+
+        self.queue.submit(std::iter::once(encoder.finish()));
+
+        let slice = readback_buffer.slice(..);
+        let (tx, rx) = futures_intrusive::channel::shared::oneshot_channel();
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            let _ = tx.send(result);
+        });
+        self.device.poll(wgpu::Maintain::Wait);
+        rx.receive().await.unwrap().unwrap();
+
+        let padded = slice.get_mapped_range();
+        let mut pixels = Vec::with_capacity((width * height * 4) as usize);
+        for row in padded.chunks_exact(padded_bytes_per_row as usize) {
+            pixels.extend_from_slice(&row[..unpadded_bytes_per_row as usize]);
         }
+        drop(padded);
+        readback_buffer.unmap();
+
+        image::RgbaImage::from_raw(width, height, pixels)
+            .expect("readback buffer size matches width*height*4")
     }
 }
\ No newline at end of file
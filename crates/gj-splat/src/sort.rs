@@ -0,0 +1,475 @@
+use wgpu::util::DeviceExt;
+use gj_core::gaussian_cloud::GaussianCloud;
+use crate::camera::Camera;
+
+/// Below this many Gaussians, sorting on the CPU is faster than paying for buffer uploads and
+/// nine compute dispatches (one key pass, then a histogram and a scatter per radix pass), so [`sort_indices`] picks the backend based on cloud size.
+const GPU_SORT_THRESHOLD: usize = 20_000;
+
+const RADIX_BITS: u32 = 8;
+const RADIX_BUCKETS: u32 = 1 << RADIX_BITS;
+const RADIX_PASSES: u32 = 32 / RADIX_BITS;
+const WORKGROUP_SIZE: u32 = 256;
+
+/// Per-Gaussian back-to-front sort key: view-space depth (`dot(view_row2, position)`, i.e. the
+/// position's view-space z read off the camera's own view matrix), quantized to a u32 so it can
+/// be radix-sorted. Larger keys are farther from the camera; [`cpu_sort_indices`] sorts ascending
+/// then reverses to get far-first. `compute_keys` in `depth_key.wgsl` computes the same view-space
+/// depth on the GPU for the large-cloud path, but quantizes with the opposite sense (farther =
+/// smaller key), since the GPU radix sorter's scatter only ever produces ascending order.
+pub fn depth_key(position: [f32; 3], view_row2: [f32; 4]) -> u32 {
+    let view_z = view_row2[0] * position[0]
+        + view_row2[1] * position[1]
+        + view_row2[2] * position[2]
+        + view_row2[3];
+    // Map depth (roughly -far..far) into a monotonic u32 range: offset then scale so that
+    // ordering by the resulting bits matches ordering by depth.
+    let depth = -view_z;
+    let shifted = depth.clamp(-10_000.0, 10_000.0) + 10_000.0;
+    (shifted * 1000.0) as u32
+}
+
+/// Row 2 of `camera.view_matrix()`: dotted with a homogeneous position, this gives the
+/// position's view-space z directly, without needing the camera's position/forward separately.
+fn view_row2(camera: &Camera) -> [f32; 4] {
+    let view = camera.view_matrix();
+    [view.x_axis.z, view.y_axis.z, view.z_axis.z, view.w_axis.z]
+}
+
+/// Back-to-front draw order for a frame, produced by either backend of [`sort_indices`]. The GPU
+/// path's buffer is bound directly as the gather pass's `order` buffer; the CPU path's indices are
+/// uploaded to a storage buffer by the caller and bound the same way.
+pub enum SortedOrder {
+    Cpu(Vec<u32>),
+    Gpu(wgpu::Buffer),
+}
+
+/// Compute back-to-front draw order for `positions` as seen from `camera`. Below
+/// [`GPU_SORT_THRESHOLD`], keys are computed and sorted on the CPU; above it, keys are computed
+/// by a compute shader straight from `instance_buffer` (so large clouds never round-trip
+/// positions through the CPU) and sorted by the GPU radix sorter.
+pub fn sort_indices(
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    encoder: &mut wgpu::CommandEncoder,
+    positions: &[[f32; 3]],
+    instance_buffer: &wgpu::Buffer,
+    camera: &Camera,
+) -> SortedOrder {
+    let row2 = view_row2(camera);
+
+    if positions.len() < GPU_SORT_THRESHOLD {
+        let keys: Vec<u32> = positions.iter().map(|&p| depth_key(p, row2)).collect();
+        SortedOrder::Cpu(cpu_sort_indices(&keys))
+    } else {
+        let n = positions.len() as u32;
+        let keys_buffer = GpuDepthKeyComputer::new(device).compute(device, encoder, instance_buffer, n, row2);
+        let buffer = GpuRadixSorter::new(device).sort(device, queue, encoder, keys_buffer, n);
+        SortedOrder::Gpu(buffer)
+    }
+}
+
+/// CPU fallback: sort indices back-to-front by depth key. Used below [`GPU_SORT_THRESHOLD`].
+///
+/// Keys are larger for farther Gaussians (see [`depth_key`]), so a plain ascending sort would
+/// give near-to-front order - the opposite of what the renderer's premultiplied-OVER blend
+/// (`src: One`, `dst: OneMinusSrcAlpha`, depth write disabled) requires. Reverse after sorting so
+/// the farthest Gaussian (largest key) is gathered first.
+pub fn cpu_sort_indices(keys: &[u32]) -> Vec<u32> {
+    let mut indices: Vec<u32> = (0..keys.len() as u32).collect();
+    indices.sort_by_key(|&i| keys[i as usize]);
+    indices.reverse();
+    indices
+}
+
+/// Map an IEEE-754 f32 to a u32 such that unsigned-integer ordering matches float ordering: flip
+/// the sign bit for positive numbers, and flip every bit for negative numbers.
+fn sortable_key(value: f32) -> u32 {
+    let bits = value.to_bits();
+    if bits & 0x8000_0000 == 0 {
+        bits | 0x8000_0000
+    } else {
+        !bits
+    }
+}
+
+/// LSD radix sort over `keys`, ascending, as [`RADIX_PASSES`] count-sort passes of
+/// [`RADIX_BITS`] bits each. Stable, so ties keep their original relative order.
+fn radix_sort_ascending(keys: &[u32]) -> Vec<u32> {
+    let n = keys.len();
+    let mut indices: Vec<u32> = (0..n as u32).collect();
+    let mut src_keys = keys.to_vec();
+    let mut scratch_keys = vec![0u32; n];
+    let mut scratch_indices = vec![0u32; n];
+
+    for pass in 0..RADIX_PASSES {
+        let shift = pass * RADIX_BITS;
+        let mut offsets = vec![0usize; RADIX_BUCKETS as usize];
+        for &key in &src_keys {
+            offsets[((key >> shift) & (RADIX_BUCKETS - 1)) as usize] += 1;
+        }
+        let mut running = 0;
+        for offset in offsets.iter_mut() {
+            let count = *offset;
+            *offset = running;
+            running += count;
+        }
+        for i in 0..n {
+            let bucket = ((src_keys[i] >> shift) & (RADIX_BUCKETS - 1)) as usize;
+            let dst = offsets[bucket];
+            offsets[bucket] += 1;
+            scratch_keys[dst] = src_keys[i];
+            scratch_indices[dst] = indices[i];
+        }
+        std::mem::swap(&mut src_keys, &mut scratch_keys);
+        std::mem::swap(&mut indices, &mut scratch_indices);
+    }
+
+    indices
+}
+
+/// Bake a far-to-near draw order for `cloud` as seen from `camera`, via a CPU LSD radix sort over
+/// view-space depth (`-z` in `camera.view_matrix()` space, so farther Gaussians sort first).
+/// Unlike [`sort_indices`]'s per-frame GPU/CPU hybrid tuned for live draw calls, this is meant for
+/// one-time bakes — pair it with [`GaussianCloud::reorder`] to permanently sort a cloud's arrays.
+pub fn depth_order(cloud: &GaussianCloud, camera: &Camera) -> Vec<u32> {
+    let view = camera.view_matrix();
+    let keys: Vec<u32> = cloud.positions.iter()
+        .map(|&position| {
+            let view_pos = view.transform_point3(glam::Vec3::from(position));
+            sortable_key(-view_pos.z)
+        })
+        .collect();
+
+    let mut order = radix_sort_ascending(&keys);
+    order.reverse();
+    order
+}
+
+/// GPU least-significant-digit radix sort over 32-bit depth keys, run as 4 passes of 8 bits
+/// each. Each pass: a compute-shader histogram of the current digit, a prefix sum over the 256
+/// bucket counts, and a scatter of (key, index) pairs into a ping-pong buffer at their bucket's
+/// running offset. The final pass's output buffer holds indices in back-to-front order.
+///
+/// The histogram is accumulated by all workgroups into a shared storage buffer, but the scan and
+/// scatter run in a single workgroup with the bucket offsets in shared memory, which keeps the scan step a cheap
+/// sequential pass over 256 buckets rather than a full multi-workgroup decoupled look-back scan.
+/// That's sufficient for the splat counts this viewer targets (hundreds of thousands of
+/// Gaussians); a full streaming scan would be needed to scale further.
+pub struct GpuRadixSorter {
+    histogram_pipeline: wgpu::ComputePipeline,
+    scatter_pipeline: wgpu::ComputePipeline,
+    bind_group_layout: wgpu::BindGroupLayout,
+}
+
+impl GpuRadixSorter {
+    pub fn new(device: &wgpu::Device) -> Self {
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Radix Sort Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("../shaders/radix_sort.wgsl").into()),
+        });
+
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Radix Sort Bind Group Layout"),
+            entries: &(0..5)
+                .map(|binding| wgpu::BindGroupLayoutEntry {
+                    binding,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                })
+                .collect::<Vec<_>>(),
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Radix Sort Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[wgpu::PushConstantRange {
+                stages: wgpu::ShaderStages::COMPUTE,
+                range: 0..4,
+            }],
+        });
+
+        let histogram_pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("Radix Histogram Pipeline"),
+            layout: Some(&pipeline_layout),
+            module: &shader,
+            entry_point: Some("histogram"),
+            compilation_options: Default::default(),
+            cache: None,
+        });
+
+        let scatter_pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("Radix Scatter Pipeline"),
+            layout: Some(&pipeline_layout),
+            module: &shader,
+            entry_point: Some("scatter"),
+            compilation_options: Default::default(),
+            cache: None,
+        });
+
+        Self { histogram_pipeline, scatter_pipeline, bind_group_layout }
+    }
+
+    /// Sort `keys_buffer` (already populated on the GPU, e.g. by [`GpuDepthKeyComputer`]) in
+    /// place of the caller, returning the buffer of sorted instance indices.
+    pub fn sort(
+        &self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        encoder: &mut wgpu::CommandEncoder,
+        keys_buffer: wgpu::Buffer,
+        n: u32,
+    ) -> wgpu::Buffer {
+        let indices: Vec<u32> = (0..n).collect();
+
+        let mut keys_buf = [
+            keys_buffer,
+            self.make_buffer(device, &vec![0u32; n as usize], "Radix Keys B"),
+        ];
+        let mut indices_buf = [
+            self.make_buffer(device, &indices, "Radix Indices A"),
+            self.make_buffer(device, &indices, "Radix Indices B"),
+        ];
+        let histogram_buf = self.make_buffer(device, &vec![0u32; RADIX_BUCKETS as usize], "Radix Histogram");
+
+        let workgroups = n.div_ceil(WORKGROUP_SIZE).max(1);
+
+        for pass in 0..RADIX_PASSES {
+            let shift = pass * RADIX_BITS;
+            let (src_keys, dst_keys) = (&keys_buf[0], &keys_buf[1]);
+            let (src_indices, dst_indices) = (&indices_buf[0], &indices_buf[1]);
+
+            let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("Radix Sort Bind Group"),
+                layout: &self.bind_group_layout,
+                entries: &[
+                    wgpu::BindGroupEntry { binding: 0, resource: src_keys.as_entire_binding() },
+                    wgpu::BindGroupEntry { binding: 1, resource: dst_keys.as_entire_binding() },
+                    wgpu::BindGroupEntry { binding: 2, resource: src_indices.as_entire_binding() },
+                    wgpu::BindGroupEntry { binding: 3, resource: dst_indices.as_entire_binding() },
+                    wgpu::BindGroupEntry { binding: 4, resource: histogram_buf.as_entire_binding() },
+                ],
+            });
+
+            queue.write_buffer(&histogram_buf, 0, bytemuck::cast_slice(&vec![0u32; RADIX_BUCKETS as usize]));
+
+            {
+                let mut pass_enc = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                    label: Some("Radix Histogram Pass"),
+                    timestamp_writes: None,
+                });
+                pass_enc.set_pipeline(&self.histogram_pipeline);
+                pass_enc.set_bind_group(0, &bind_group, &[]);
+                pass_enc.set_push_constants(0, bytemuck::cast_slice(&[shift]));
+                pass_enc.dispatch_workgroups(workgroups, 1, 1);
+            }
+            {
+                let mut pass_enc = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                    label: Some("Radix Scatter Pass"),
+                    timestamp_writes: None,
+                });
+                pass_enc.set_pipeline(&self.scatter_pipeline);
+                pass_enc.set_bind_group(0, &bind_group, &[]);
+                pass_enc.set_push_constants(0, bytemuck::cast_slice(&[shift]));
+                pass_enc.dispatch_workgroups(1, 1, 1);
+            }
+
+            keys_buf.swap(0, 1);
+            indices_buf.swap(0, 1);
+        }
+
+        // Each pass writes into slot 1 and the swap above moves that output into slot 0, so the
+        // fully-sorted indices end up in slot 0. Drop the scratch keys buffers; only the sorted index order is needed downstream.
+        drop(keys_buf);
+        let [sorted_indices, _scratch] = indices_buf;
+        sorted_indices
+    }
+
+    fn make_buffer<T: bytemuck::Pod>(&self, device: &wgpu::Device, data: &[T], label: &str) -> wgpu::Buffer {
+        device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some(label),
+            contents: bytemuck::cast_slice(data),
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC | wgpu::BufferUsages::COPY_DST,
+        })
+    }
+}
+
+/// Computes [`GpuRadixSorter`]'s input keys on the GPU, straight from the instance buffer's
+/// positions and the camera's view matrix — so sorting a large cloud never has to read its
+/// positions back to the CPU first. See `depth_key.wgsl`.
+pub struct GpuDepthKeyComputer {
+    pipeline: wgpu::ComputePipeline,
+    bind_group_layout: wgpu::BindGroupLayout,
+}
+
+impl GpuDepthKeyComputer {
+    pub fn new(device: &wgpu::Device) -> Self {
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Depth Key Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("../shaders/depth_key.wgsl").into()),
+        });
+
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Depth Key Bind Group Layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Depth Key Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[wgpu::PushConstantRange {
+                stages: wgpu::ShaderStages::COMPUTE,
+                range: 0..16,
+            }],
+        });
+
+        let pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("Depth Key Pipeline"),
+            layout: Some(&pipeline_layout),
+            module: &shader,
+            entry_point: Some("compute_keys"),
+            compilation_options: Default::default(),
+            cache: None,
+        });
+
+        Self { pipeline, bind_group_layout }
+    }
+
+    /// Dispatch the key-computation pass for `count` instances in `instance_buffer`, returning a
+    /// freshly-allocated keys buffer sized for the radix sorter to consume directly.
+    pub fn compute(
+        &self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        instance_buffer: &wgpu::Buffer,
+        count: u32,
+        view_row2: [f32; 4],
+    ) -> wgpu::Buffer {
+        let keys_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Depth Keys Buffer"),
+            size: (count as u64) * std::mem::size_of::<u32>() as u64,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Depth Key Bind Group"),
+            layout: &self.bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry { binding: 0, resource: instance_buffer.as_entire_binding() },
+                wgpu::BindGroupEntry { binding: 1, resource: keys_buffer.as_entire_binding() },
+            ],
+        });
+
+        let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+            label: Some("Depth Key Pass"),
+            timestamp_writes: None,
+        });
+        pass.set_pipeline(&self.pipeline);
+        pass.set_bind_group(0, &bind_group, &[]);
+        pass.set_push_constants(0, bytemuck::cast_slice(&view_row2));
+        pass.dispatch_workgroups(count.div_ceil(WORKGROUP_SIZE).max(1), 1, 1);
+        drop(pass);
+
+        keys_buffer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cpu_sort_orders_back_to_front() {
+        // Key 30 is farthest, so index 0 (key 30) must be gathered first, then index 2 (key 20),
+        // then index 1 (key 10, nearest) last.
+        let keys = vec![30, 10, 20];
+        let order = cpu_sort_indices(&keys);
+        assert_eq!(order, vec![0, 2, 1]);
+    }
+
+    #[test]
+    fn test_depth_key_increases_with_distance() {
+        let camera = Camera::new(glam::Vec3::ZERO, 10.0);
+        let row2 = view_row2(&camera);
+
+        // Camera sits at world z=10 looking at the origin, so z=5 is nearer than z=1.
+        let near = depth_key([0.0, 0.0, 5.0], row2);
+        let far = depth_key([0.0, 0.0, 1.0], row2);
+        assert!(far > near);
+    }
+
+    #[test]
+    fn test_sortable_key_preserves_float_order() {
+        let values = [-100.0f32, -1.0, 0.0, 0.5, 42.0];
+        let mut keys: Vec<u32> = values.iter().map(|&v| sortable_key(v)).collect();
+        keys.sort();
+        let resorted: Vec<u32> = {
+            let mut v = values.to_vec();
+            v.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            v.iter().map(|&x| sortable_key(x)).collect()
+        };
+        assert_eq!(keys, resorted);
+    }
+
+    #[test]
+    fn test_depth_order_is_far_to_near() {
+        use gj_core::gaussian_cloud::GaussianCloud;
+
+        let mut cloud = GaussianCloud::new();
+        cloud.add_gaussian([0.0, 0.0, 1.0], [1.0; 3], [1.0, 0.0, 0.0, 0.0], [1.0; 3], 1.0);
+        cloud.add_gaussian([0.0, 0.0, 5.0], [1.0; 3], [1.0, 0.0, 0.0, 0.0], [1.0; 3], 1.0);
+        cloud.add_gaussian([0.0, 0.0, 3.0], [1.0; 3], [1.0, 0.0, 0.0, 0.0], [1.0; 3], 1.0);
+
+        let camera = Camera::new(glam::Vec3::ZERO, 10.0);
+        let order = depth_order(&cloud, &camera);
+
+        // Camera sits at world z=10 looking toward the origin, so the Gaussian at world z=1 is
+        // farthest away (distance 9), z=3 is in the middle (distance 7), z=5 is nearest (5).
+        assert_eq!(order, vec![0, 2, 1]);
+    }
+
+    #[test]
+    fn test_depth_order_reorder_round_trip() {
+        use gj_core::gaussian_cloud::GaussianCloud;
+
+        let mut cloud = GaussianCloud::new();
+        // idx0 sits nearer the camera (world z=5, distance 5), idx1 is farther (z=1, distance 9).
+        cloud.add_gaussian([0.0, 0.0, 5.0], [1.0; 3], [1.0, 0.0, 0.0, 0.0], [0.1, 0.0, 0.0], 1.0);
+        cloud.add_gaussian([0.0, 0.0, 1.0], [1.0; 3], [1.0, 0.0, 0.0, 0.0], [0.2, 0.0, 0.0], 1.0);
+
+        let camera = Camera::new(glam::Vec3::ZERO, 10.0);
+        let order = depth_order(&cloud, &camera);
+        cloud.reorder(&order);
+
+        // Far-to-near, so idx1 (farther) should now be first.
+        assert_eq!(cloud.colors[0], [0.2, 0.0, 0.0]);
+        assert_eq!(cloud.colors[1], [0.1, 0.0, 0.0]);
+    }
+}
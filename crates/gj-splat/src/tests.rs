@@ -1,6 +1,9 @@
 #[cfg(test)]
 mod tests {
     use crate::camera::Camera;
+    use crate::camera_path::CameraPath;
+    use crate::renderer::{evaluate_colors, select_in_frustum};
+    use gj_core::gaussian_cloud::GaussianCloud;
     use super::*;
 
     #[test]
@@ -16,4 +19,120 @@ mod tests {
         assert_eq!(camera.azimuth, 45.0);
         assert_eq!(camera.elevation, 30.0);
     }
+
+    #[test]
+    fn test_evaluate_colors_falls_back_without_sh() {
+        let mut cloud = GaussianCloud::new();
+        cloud.add_gaussian([0.0; 3], [1.0; 3], [1.0, 0.0, 0.0, 0.0], [0.2, 0.4, 0.6], 1.0);
+
+        let camera = Camera::new(glam::Vec3::ZERO, 3.0);
+        let colors = evaluate_colors(&cloud, &camera);
+
+        assert_eq!(colors, vec![[0.2, 0.4, 0.6]]);
+    }
+
+    #[test]
+    fn test_evaluate_colors_uses_sh_when_present() {
+        let mut cloud = GaussianCloud::new();
+        cloud.add_gaussian_with_sh(
+            [0.0; 3],
+            [1.0; 3],
+            [1.0, 0.0, 0.0, 0.0],
+            vec![0.5, 0.3, 0.1],
+            1.0,
+        );
+
+        let camera = Camera::new(glam::Vec3::ZERO, 3.0);
+        let colors = evaluate_colors(&cloud, &camera);
+
+        assert_eq!(colors.len(), 1);
+        assert!(colors[0].iter().all(|c| (0.0..=1.0).contains(c)));
+    }
+
+    #[test]
+    fn test_evaluate_colors_uses_camera_to_gaussian_direction() {
+        let mut cloud = GaussianCloud::new();
+        // Camera orbits the origin at distance 3 with azimuth/elevation 0, so it sits at
+        // (0, 0, 3). This Gaussian sits off to the side along x, giving a non-degenerate view
+        // direction of normalize([5, 0, 3] - [0, 0, 3]) = [1, 0, 0] - not the [0, 0, 1] fallback
+        // a same-position camera/Gaussian pair would hit, and not the origin-to-origin case that
+        // let the camera-to-Gaussian sign flip go unnoticed.
+        let sh_coeffs = vec![
+            0.0, 0.0, 0.0, // degree 0 (DC)
+            0.0, 0.0, 0.0, // degree 1, y term
+            0.0, 0.0, 0.0, // degree 1, z term
+            1.0, -1.0, 0.0, // degree 1, x term
+        ];
+        cloud.add_gaussian_with_sh([5.0, 0.0, 3.0], [1.0; 3], [1.0, 0.0, 0.0, 0.0], sh_coeffs, 1.0);
+
+        let camera = Camera::new(glam::Vec3::ZERO, 3.0);
+        let colors = evaluate_colors(&cloud, &camera);
+
+        assert_eq!(colors.len(), 1);
+        // The x-band term (-SH_C1 * x) is negative for dir.x > 0, so the +1.0 R coefficient is
+        // pulled below the 0.5 baseline and the -1.0 G coefficient is pushed above it. Using the
+        // Gaussian-to-camera direction instead (the bug this guards against) would flip dir.x's
+        // sign and swap which channel ends up higher.
+        assert!(colors[0][0] < 0.5, "R channel should be pulled below 0.5: {:?}", colors[0]);
+        assert!(colors[0][1] > 0.5, "G channel should be pushed above 0.5: {:?}", colors[0]);
+        assert!((colors[0][2] - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_select_in_frustum_drops_behind_camera() {
+        let mut cloud = GaussianCloud::new();
+        // In view: sits between the camera and its target.
+        cloud.add_gaussian([0.0, 0.0, 5.0], [1.0; 3], [1.0, 0.0, 0.0, 0.0], [1.0; 3], 1.0);
+        // Behind the camera: should fail the near/far planes.
+        cloud.add_gaussian([0.0, 0.0, 20.0], [1.0; 3], [1.0, 0.0, 0.0, 0.0], [1.0; 3], 1.0);
+
+        let mut camera = Camera::new(glam::Vec3::ZERO, 10.0);
+        camera.aspect_ratio = 1.0;
+        camera.near = 0.1;
+        camera.far = 8.0;
+
+        let selected = select_in_frustum(&cloud, &camera);
+        assert_eq!(selected, vec![0]);
+    }
+
+    #[test]
+    fn test_turntable_path_sweeps_full_orbit() {
+        let path = CameraPath::turntable(glam::Vec3::ZERO, 5.0, 10.0, 4);
+        let template = Camera::default();
+
+        let start = path.sample(0.0, &template);
+        let end = path.sample(1.0, &template);
+
+        assert!((start.azimuth - 0.0).abs() < 1e-3);
+        assert!((end.azimuth - 360.0).abs() < 1e-3 || (end.azimuth - 0.0).abs() < 1e-3);
+        assert!((start.distance - 5.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_camera_path_wraps_azimuth_the_short_way() {
+        let path = CameraPath::new(vec![
+            crate::camera_path::CameraKeyframe {
+                time: 0.0,
+                target: glam::Vec3::ZERO,
+                distance: 5.0,
+                azimuth: 350.0,
+                elevation: 0.0,
+                fov: 50.0,
+            },
+            crate::camera_path::CameraKeyframe {
+                time: 1.0,
+                target: glam::Vec3::ZERO,
+                distance: 5.0,
+                azimuth: 10.0,
+                elevation: 0.0,
+                fov: 50.0,
+            },
+        ]);
+        let template = Camera::default();
+
+        let mid = path.sample(0.5, &template);
+        // Interpolating the short way (350 -> 370) should land near 0/360, not near 180.
+        let distance_from_zero = mid.azimuth.min(360.0 - mid.azimuth);
+        assert!(distance_from_zero < 15.0, "azimuth {} took the long way around", mid.azimuth);
+    }
 }
\ No newline at end of file